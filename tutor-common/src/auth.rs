@@ -0,0 +1,265 @@
+//! JWT 기반 tutor/학생 인증.
+//!
+//! 토큰 발급은 로그인을 처리하는 서비스가, 검증은 `AuthenticatedTutor`, `AuthenticatedStudent`
+//! 추출자가 담당한다. 두 곳 모두 앱 데이터로 등록된 `JwtKeys`를 사용한다.
+
+use super::errors::EzyTutorError;
+use actix_web::{dev::Payload, http::header, web, FromRequest, HttpRequest};
+use chrono::Utc;
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use std::future::{ready, Ready};
+use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, ToSchema};
+
+/// 발급한 토큰의 유효 기간 (초)
+pub const TOKEN_LIFETIME_SECS: i64 = 60 * 60;
+
+/// OpenAPI 문서에서 사용하는 보안 스키마 이름
+pub const BEARER_AUTH: &str = "bearer_auth";
+
+/// 토큰 주체의 종류. tutor와 학생의 id는 서로 겹칠 수 있으므로 함께 검증한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    Tutor,
+    Student,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    pub role: Role,
+    pub iat: i64,
+    pub exp: i64,
+}
+
+impl Claims {
+    /// `role` 토큰이면 주체의 id를, 다른 종류의 토큰이면 403을 돌려준다.
+    pub fn subject(&self, role: Role) -> Result<i32, EzyTutorError> {
+        if self.role != role {
+            return Err(EzyTutorError::Forbidden(format!(
+                "Token is not issued for a {:?}",
+                role
+            )));
+        }
+        self.sub
+            .parse()
+            .map_err(|_| EzyTutorError::Unauthorized("Invalid token subject".into()))
+    }
+}
+
+/// HS256 서명/검증 키
+pub struct JwtKeys {
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+}
+
+impl JwtKeys {
+    pub fn new(secret: &[u8]) -> Self {
+        JwtKeys {
+            encoding: EncodingKey::from_secret(secret),
+            decoding: DecodingKey::from_secret(secret),
+        }
+    }
+
+    pub fn issue(&self, tutor_id: i32) -> Result<String, EzyTutorError> {
+        self.issue_for(Role::Tutor, tutor_id)
+    }
+
+    pub fn issue_student(&self, student_id: i32) -> Result<String, EzyTutorError> {
+        self.issue_for(Role::Student, student_id)
+    }
+
+    fn issue_for(&self, role: Role, id: i32) -> Result<String, EzyTutorError> {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            sub: id.to_string(),
+            role,
+            iat: now,
+            exp: now + TOKEN_LIFETIME_SECS,
+        };
+        encode(&Header::new(Algorithm::HS256), &claims, &self.encoding)
+            .map_err(|error| EzyTutorError::ActixError(error.to_string()))
+    }
+
+    pub fn verify(&self, token: &str) -> Result<Claims, EzyTutorError> {
+        decode::<Claims>(token, &self.decoding, &Validation::new(Algorithm::HS256))
+            .map(|data| data.claims)
+            .map_err(|error| EzyTutorError::Unauthorized(format!("Invalid token: {}", error)))
+    }
+}
+
+/// 로그인/가입 성공 시 돌려주는 토큰
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TokenResponse {
+    pub tutor_id: i32,
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+impl TokenResponse {
+    pub fn new(tutor_id: i32, access_token: String) -> Self {
+        TokenResponse {
+            tutor_id,
+            access_token,
+            token_type: "Bearer".into(),
+            expires_in: TOKEN_LIFETIME_SECS,
+        }
+    }
+}
+
+/// 학생 가입/로그인 성공 시 돌려주는 토큰
+#[derive(Debug, Serialize, ToSchema)]
+pub struct StudentTokenResponse {
+    pub student_id: i32,
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+impl StudentTokenResponse {
+    pub fn new(student_id: i32, access_token: String) -> Self {
+        StudentTokenResponse {
+            student_id,
+            access_token,
+            token_type: "Bearer".into(),
+            expires_in: TOKEN_LIFETIME_SECS,
+        }
+    }
+}
+
+/// `Authorization: Bearer <token>` 헤더의 토큰을 검증한다.
+fn bearer_claims(req: &HttpRequest) -> Result<Claims, EzyTutorError> {
+    let keys = req
+        .app_data::<web::Data<JwtKeys>>()
+        .ok_or_else(|| EzyTutorError::ActixError("JwtKeys is not configured".into()))?;
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| EzyTutorError::Unauthorized("Missing bearer token".into()))?;
+    keys.verify(token.trim())
+}
+
+/// `Authorization: Bearer <token>` 헤더로 인증된 tutor
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedTutor {
+    pub tutor_id: i32,
+}
+
+impl AuthenticatedTutor {
+    /// 다른 tutor의 자원에 접근하려 하면 403을 돌려준다.
+    pub fn ensure_owner(&self, tutor_id: i32) -> Result<(), EzyTutorError> {
+        if self.tutor_id == tutor_id {
+            Ok(())
+        } else {
+            Err(EzyTutorError::Forbidden(format!(
+                "Tutor {} cannot modify resources of tutor {}",
+                self.tutor_id, tutor_id
+            )))
+        }
+    }
+
+    fn from_request(req: &HttpRequest) -> Result<Self, EzyTutorError> {
+        Ok(AuthenticatedTutor {
+            tutor_id: bearer_claims(req)?.subject(Role::Tutor)?,
+        })
+    }
+}
+
+impl FromRequest for AuthenticatedTutor {
+    type Error = EzyTutorError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(AuthenticatedTutor::from_request(req))
+    }
+}
+
+/// `Authorization: Bearer <token>` 헤더로 인증된 학생
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedStudent {
+    pub student_id: i32,
+}
+
+impl AuthenticatedStudent {
+    /// 다른 학생의 자원에 접근하려 하면 403을 돌려준다.
+    pub fn ensure_owner(&self, student_id: i32) -> Result<(), EzyTutorError> {
+        if self.student_id == student_id {
+            Ok(())
+        } else {
+            Err(EzyTutorError::Forbidden(format!(
+                "Student {} cannot modify resources of student {}",
+                self.student_id, student_id
+            )))
+        }
+    }
+
+    fn from_request(req: &HttpRequest) -> Result<Self, EzyTutorError> {
+        Ok(AuthenticatedStudent {
+            student_id: bearer_claims(req)?.subject(Role::Student)?,
+        })
+    }
+}
+
+impl FromRequest for AuthenticatedStudent {
+    type Error = EzyTutorError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(AuthenticatedStudent::from_request(req))
+    }
+}
+
+/// OpenAPI 문서에 bearer 토큰 보안 스키마를 추가한다.
+pub struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            BEARER_AUTH,
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn issued_token_is_verified_with_same_secret_only() {
+        let keys = JwtKeys::new(b"first secret");
+        let token = keys.issue(7).unwrap();
+
+        assert_eq!(
+            keys.verify(&token).unwrap().subject(Role::Tutor).unwrap(),
+            7
+        );
+        assert!(matches!(
+            JwtKeys::new(b"second secret").verify(&token),
+            Err(EzyTutorError::Unauthorized(_))
+        ));
+    }
+
+    #[test]
+    fn student_token_is_not_a_tutor_token() {
+        let keys = JwtKeys::new(b"first secret");
+        let claims = keys.verify(&keys.issue_student(7).unwrap()).unwrap();
+
+        assert_eq!(claims.subject(Role::Student).unwrap(), 7);
+        assert!(matches!(
+            claims.subject(Role::Tutor),
+            Err(EzyTutorError::Forbidden(_))
+        ));
+    }
+}
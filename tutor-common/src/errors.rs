@@ -0,0 +1,393 @@
+use super::telemetry::RequestId;
+use actix_web::{
+    body::{EitherBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    error,
+    http::{header, StatusCode},
+    middleware::Next,
+    HttpMessage, HttpRequest, HttpResponse,
+};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "postgres")]
+use sqlx::error::Error as SQLxError;
+use std::borrow::Cow;
+use std::fmt;
+use utoipa::ToSchema;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+#[derive(Debug, Serialize)]
+pub enum EzyTutorError {
+    DBError(String),
+    ActixError(String),
+    NotFound(String),
+    InvalidInput(String),
+    ValidationError(Vec<FieldError>),
+    Unauthorized(String),
+    Forbidden(String),
+    Conflict(String),
+    /// `If-Match`의 버전이 현재 버전과 다름 (412)
+    PreconditionFailed(String),
+    /// 조건부 요청이어야 하는데 `If-Match`가 없음 (428)
+    PreconditionRequired(String),
+    ServiceUnavailable(String),
+}
+
+/// 유효성 검사에 실패한 필드와 그 이유
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// RFC 7807 Problem Details 에러 응답 (`application/problem+json`)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// 서버 로그와 대조할 수 있는 요청 ID (`X-Request-Id`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+}
+
+impl ProblemDetails {
+    /// 별도 문제 유형이 없는 에러는 `about:blank`와 상태 코드의 기본 문구를 사용한다.
+    pub fn from_status(status: StatusCode, detail: impl Into<String>) -> Self {
+        ProblemDetails {
+            problem_type: "about:blank".into(),
+            title: status.canonical_reason().unwrap_or("Error").into(),
+            status: status.as_u16(),
+            detail: detail.into(),
+            instance: None,
+            request_id: None,
+            errors: vec![],
+        }
+    }
+
+    fn new(status: StatusCode, problem_type: &str, title: &str, detail: String) -> Self {
+        ProblemDetails {
+            problem_type: format!("/problems/{}", problem_type),
+            title: title.into(),
+            status: status.as_u16(),
+            detail,
+            instance: None,
+            request_id: None,
+            errors: vec![],
+        }
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    pub fn to_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(PROBLEM_JSON)
+            .json(self)
+    }
+}
+
+impl EzyTutorError {
+    pub fn problem(&self) -> ProblemDetails {
+        let status = error::ResponseError::status_code(self);
+        match self {
+            EzyTutorError::DBError(_) => ProblemDetails::new(
+                status,
+                "database-error",
+                "Database error",
+                "Database error".into(),
+            ),
+            EzyTutorError::ActixError(_) => ProblemDetails::new(
+                status,
+                "internal-error",
+                "Internal server error",
+                "Internal server error".into(),
+            ),
+            EzyTutorError::NotFound(msg) => {
+                ProblemDetails::new(status, "not-found", "Resource not found", msg.clone())
+            }
+            EzyTutorError::InvalidInput(msg) => {
+                ProblemDetails::new(status, "invalid-input", "Invalid input", msg.clone())
+            }
+            EzyTutorError::ValidationError(errors) => ProblemDetails {
+                errors: errors.clone(),
+                ..ProblemDetails::new(
+                    status,
+                    "validation-error",
+                    "Validation error",
+                    "One or more fields are invalid".into(),
+                )
+            },
+            EzyTutorError::Unauthorized(msg) => ProblemDetails::new(
+                status,
+                "unauthorized",
+                "Authentication required",
+                msg.clone(),
+            ),
+            EzyTutorError::Forbidden(msg) => {
+                ProblemDetails::new(status, "forbidden", "Forbidden", msg.clone())
+            }
+            EzyTutorError::Conflict(msg) => {
+                ProblemDetails::new(status, "conflict", "Conflict", msg.clone())
+            }
+            EzyTutorError::PreconditionFailed(msg) => ProblemDetails::new(
+                status,
+                "precondition-failed",
+                "Precondition failed",
+                msg.clone(),
+            ),
+            EzyTutorError::PreconditionRequired(msg) => ProblemDetails::new(
+                status,
+                "precondition-required",
+                "Precondition required",
+                msg.clone(),
+            ),
+            EzyTutorError::ServiceUnavailable(_) => ProblemDetails::new(
+                status,
+                "service-unavailable",
+                "Service unavailable",
+                "Service is temporarily overloaded, please retry later".into(),
+            ),
+        }
+    }
+
+    fn log(&self) {
+        match self {
+            EzyTutorError::DBError(msg) => tracing::error!(error = %msg, "Database error occurred"),
+            EzyTutorError::ActixError(msg) => {
+                tracing::error!(error = %msg, "Server error occurred")
+            }
+            EzyTutorError::NotFound(msg) => {
+                tracing::info!(error = %msg, "Not found error occurred")
+            }
+            EzyTutorError::InvalidInput(msg) => {
+                tracing::info!(error = %msg, "Invalid parameters received")
+            }
+            EzyTutorError::ValidationError(errors) => {
+                tracing::info!(errors = ?errors, "Validation error occurred")
+            }
+            EzyTutorError::Unauthorized(msg) => {
+                tracing::info!(error = %msg, "Unauthorized request")
+            }
+            EzyTutorError::Forbidden(msg) => tracing::info!(error = %msg, "Forbidden request"),
+            EzyTutorError::Conflict(msg) => tracing::info!(error = %msg, "Conflicting request"),
+            EzyTutorError::PreconditionFailed(msg) => {
+                tracing::info!(error = %msg, "Precondition failed")
+            }
+            EzyTutorError::PreconditionRequired(msg) => {
+                tracing::info!(error = %msg, "Precondition required")
+            }
+            EzyTutorError::ServiceUnavailable(msg) => {
+                tracing::warn!(error = %msg, "Service unavailable")
+            }
+        }
+    }
+}
+
+impl error::ResponseError for EzyTutorError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            EzyTutorError::DBError(_) | EzyTutorError::ActixError(_) => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+            EzyTutorError::NotFound(_) => StatusCode::NOT_FOUND,
+            EzyTutorError::InvalidInput(_) | EzyTutorError::ValidationError(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            EzyTutorError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            EzyTutorError::Forbidden(_) => StatusCode::FORBIDDEN,
+            EzyTutorError::Conflict(_) => StatusCode::CONFLICT,
+            EzyTutorError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            EzyTutorError::PreconditionRequired(_) => StatusCode::PRECONDITION_REQUIRED,
+            EzyTutorError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+    fn error_response(&self) -> HttpResponse<actix_web::body::BoxBody> {
+        self.log();
+        let mut response = self.problem().to_response();
+        match self {
+            EzyTutorError::Unauthorized(_) => {
+                response.headers_mut().insert(
+                    header::WWW_AUTHENTICATE,
+                    header::HeaderValue::from_static("Bearer"),
+                );
+            }
+            EzyTutorError::ServiceUnavailable(_) => {
+                response
+                    .headers_mut()
+                    .insert(header::RETRY_AFTER, header::HeaderValue::from_static("1"));
+            }
+            _ => {}
+        }
+        response
+    }
+}
+
+/// 모든 에러 응답을 Problem Details 형식으로 맞추는 미들웨어.
+///
+/// `EzyTutorError`에는 요청 경로(`instance`)와 요청 ID를 채우고, 추출자 등 다른 곳에서 발생한
+/// actix 에러는 상태 코드 기반 Problem Details로 바꾼다. 원래 응답의 헤더는 유지한다.
+pub async fn problem_details(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, actix_web::Error> {
+    let instance = req.path().to_owned();
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map(|request_id| request_id.to_string());
+    let res = next.call(req).await?;
+
+    let problem = match res.response().error() {
+        Some(error) => match error.as_error::<EzyTutorError>() {
+            Some(error) => error.problem(),
+            None => {
+                let status = res.status();
+                let detail = if status.is_server_error() {
+                    tracing::error!(error = ?error, "Server error occurred");
+                    "Internal server error".to_string()
+                } else {
+                    error.to_string()
+                };
+                ProblemDetails::from_status(status, detail)
+            }
+        },
+        None => return Ok(res.map_into_left_body()),
+    };
+
+    let problem = ProblemDetails {
+        instance: Some(instance),
+        request_id,
+        ..problem
+    };
+    let mut response = problem.to_response();
+    for (name, value) in res.headers() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            response.headers_mut().insert(name.clone(), value.clone());
+        }
+    }
+    Ok(res.into_response(response).map_into_right_body())
+}
+
+impl fmt::Display for EzyTutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EzyTutorError::DBError(msg)
+            | EzyTutorError::ActixError(msg)
+            | EzyTutorError::NotFound(msg)
+            | EzyTutorError::InvalidInput(msg)
+            | EzyTutorError::Unauthorized(msg)
+            | EzyTutorError::Forbidden(msg)
+            | EzyTutorError::Conflict(msg)
+            | EzyTutorError::PreconditionFailed(msg)
+            | EzyTutorError::PreconditionRequired(msg)
+            | EzyTutorError::ServiceUnavailable(msg) => write!(f, "{}", msg),
+            EzyTutorError::ValidationError(errors) => write!(
+                f,
+                "{}",
+                errors
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl From<actix_web::error::Error> for EzyTutorError {
+    fn from(error: actix_web::error::Error) -> Self {
+        EzyTutorError::ActixError(error.to_string())
+    }
+}
+
+impl From<error::BlockingError> for EzyTutorError {
+    fn from(error: error::BlockingError) -> Self {
+        EzyTutorError::ActixError(error.to_string())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<SQLxError> for EzyTutorError {
+    fn from(error: SQLxError) -> Self {
+        match error {
+            // 커넥션 풀이 모두 사용 중이면 잠시 후 다시 시도할 수 있는 상태로 응답
+            SQLxError::PoolTimedOut | SQLxError::PoolClosed => {
+                EzyTutorError::ServiceUnavailable(error.to_string())
+            }
+            error => EzyTutorError::DBError(error.to_string()),
+        }
+    }
+}
+
+impl From<ValidationErrors> for EzyTutorError {
+    fn from(errors: ValidationErrors) -> Self {
+        EzyTutorError::ValidationError(
+            flatten_errors(&errors, None)
+                .iter()
+                .map(|(field, err)| {
+                    let code = err.code.as_ref();
+
+                    FieldError {
+                        field: field.clone(),
+                        message: err
+                            .message
+                            .as_ref()
+                            .unwrap_or(&Cow::Borrowed(code))
+                            .to_string(),
+                    }
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+fn flatten_errors(
+    errors: &ValidationErrors,
+    path: Option<String>,
+) -> Vec<(String, &validator::ValidationError)> {
+    errors
+        .errors()
+        .iter()
+        .flat_map(|(&field, err)| {
+            let actual_path = path
+                .as_ref()
+                .map(|path| [path.as_str(), field].join("."))
+                .unwrap_or_else(|| field.to_owned());
+            match err {
+                ValidationErrorsKind::Field(field_errors) => field_errors
+                    .iter()
+                    .map(|error| (actual_path.clone(), error))
+                    .collect::<Vec<_>>(),
+                ValidationErrorsKind::List(list_error) => list_error
+                    .iter()
+                    .flat_map(|(index, errors)| {
+                        let actual_path = format!("{}[{}]", actual_path.as_str(), index);
+                        flatten_errors(errors, Some(actual_path))
+                    })
+                    .collect::<Vec<_>>(),
+                ValidationErrorsKind::Struct(struct_errors) => {
+                    flatten_errors(struct_errors, Some(actual_path))
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+pub fn validation_error_handler(errors: ValidationErrors, _: &HttpRequest) -> actix_web::Error {
+    EzyTutorError::from(errors).into()
+}
@@ -0,0 +1,336 @@
+use super::auth::AuthenticatedTutor;
+use super::errors::{EzyTutorError, ProblemDetails};
+use super::models::{
+    Course, CoursePage, CourseQuery, CourseSearchPage, CourseSearchQuery, CreateCourse,
+    UpdateCourse, VersionMatch,
+};
+use super::repository::CourseRepository;
+use actix_web::{
+    delete, get,
+    http::header::{self, ETag, EntityTag, IfMatch, IfNoneMatch},
+    post, route, web, FromRequest, HttpMessage, HttpRequest, HttpResponse,
+};
+use actix_web_validation::Validated;
+use tracing::instrument;
+
+const COURSE: &str = "course";
+
+/// course 버전으로 만든 strong ETag
+fn course_etag(course: &Course) -> ETag {
+    ETag(EntityTag::new_strong(course.version.to_string()))
+}
+
+/// 수정/삭제 요청의 `If-Match`를 읽는다. 없으면 428, 형식이 잘못되면 400
+fn version_match(req: &HttpRequest) -> Result<VersionMatch, EzyTutorError> {
+    if !req.headers().contains_key(header::IF_MATCH) {
+        return Err(EzyTutorError::PreconditionRequired(
+            "If-Match header with the course ETag is required".into(),
+        ));
+    }
+    match req.get_header::<IfMatch>() {
+        Some(IfMatch::Any) => Ok(VersionMatch::Any),
+        // If-Match는 strong 비교이므로 weak ETag는 어떤 버전과도 맞지 않음
+        Some(IfMatch::Items(tags)) => Ok(VersionMatch::Versions(
+            tags.iter()
+                .filter(|tag| !tag.weak)
+                .filter_map(|tag| tag.tag().parse().ok())
+                .collect(),
+        )),
+        None => Err(EzyTutorError::InvalidInput(
+            "Invalid If-Match header".into(),
+        )),
+    }
+}
+
+/// Create new course for a tutor.
+#[utoipa::path(
+    tag = COURSE,
+    request_body = CreateCourse,
+    responses(
+        (status = 201, description = "course added successfully", body = Course,
+            headers(
+                ("Location" = String, description = "URL of the created course"),
+                ("ETag" = String, description = "version of the course"),
+            )),
+        (status = 400, description = "invalid course fields or unknown tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "course belongs to another tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/")]
+#[instrument(skip_all)]
+pub async fn post_new_course(
+    tutor: AuthenticatedTutor,
+    Validated(new_course): Validated<web::Json<CreateCourse>>,
+    repository: web::Data<dyn CourseRepository>,
+) -> Result<HttpResponse, EzyTutorError> {
+    tutor.ensure_owner(new_course.tutor_id)?;
+    repository
+        .post_new_course(new_course.try_into()?)
+        .await
+        .map(|course| {
+            HttpResponse::Created()
+                .insert_header((
+                    header::LOCATION,
+                    format!("/courses/{}/{}", course.tutor_id, course.course_id),
+                ))
+                .insert_header(course_etag(&course))
+                .json(course)
+        })
+}
+
+/// get a page of courses of tutor by given tutor id.
+#[utoipa::path(
+    tag = COURSE,
+    responses(
+        (status = 200, description = "page of matching courses, empty when nothing matches", body = CoursePage),
+        (status = 400, description = "invalid paging, sorting or filter parameters", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "deleted courses requested without a valid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "deleted courses of another tutor requested", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        CourseQuery,
+    ),
+    security((), ("bearer_auth" = []))
+)]
+#[get("/{tutor_id}")]
+#[instrument(skip_all)]
+pub async fn get_courses_for_tutor(
+    req: HttpRequest,
+    repository: web::Data<dyn CourseRepository>,
+    params: web::Path<i32>,
+    Validated(query): Validated<web::Query<CourseQuery>>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let tutor_id = params.into_inner();
+    let query = query.into_inner();
+    // 삭제된 course는 해당 tutor만 볼 수 있음
+    if query.include_deleted() {
+        AuthenticatedTutor::extract(&req)
+            .await?
+            .ensure_owner(tutor_id)?;
+    }
+    let (courses, total) = repository.get_courses_for_tutor(tutor_id, &query).await?;
+
+    let (limit, offset) = (query.limit(), query.offset());
+    let next = if offset + (courses.len() as i64) < total {
+        let next_query = CourseQuery {
+            offset: Some(offset + limit),
+            ..query
+        };
+        serde_urlencoded::to_string(&next_query)
+            .map(|query_string| format!("/courses/{}?{}", tutor_id, query_string))
+            .ok()
+    } else {
+        None
+    };
+
+    Ok(HttpResponse::Ok().json(CoursePage {
+        courses,
+        total,
+        limit,
+        offset,
+        next,
+    }))
+}
+
+/// search courses of all tutors by keyword.
+#[utoipa::path(
+    tag = COURSE,
+    responses(
+        (status = 200, description = "courses matching the keyword, most relevant first", body = CourseSearchPage),
+        (status = 400, description = "missing or invalid search parameters", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(CourseSearchQuery)
+)]
+#[get("/search")]
+#[instrument(skip_all)]
+pub async fn search_courses(
+    repository: web::Data<dyn CourseRepository>,
+    Validated(query): Validated<web::Query<CourseSearchQuery>>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let query = query.into_inner();
+    let (results, total) = repository.search_courses(&query).await?;
+
+    let (limit, offset) = (query.limit(), query.offset());
+    let next = if offset + (results.len() as i64) < total {
+        let next_query = CourseSearchQuery {
+            offset: Some(offset + limit),
+            ..query
+        };
+        serde_urlencoded::to_string(&next_query)
+            .map(|query_string| format!("/courses/search?{}", query_string))
+            .ok()
+    } else {
+        None
+    };
+
+    Ok(HttpResponse::Ok().json(CourseSearchPage {
+        results,
+        total,
+        limit,
+        offset,
+        next,
+    }))
+}
+
+/// get course by given tutor id and course id
+#[utoipa::path(
+    tag = COURSE,
+    responses(
+        (status = 200, description = "course found from storage", body = Course,
+            headers(("ETag" = String, description = "version of the course"))),
+        (status = 304, description = "course has not changed since the given ETag",
+            headers(("ETag" = String, description = "version of the course"))),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course of tutor"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of the cached course"),
+    )
+)]
+#[get("/{tutor_id}/{course_id}")]
+#[instrument(skip_all)]
+pub async fn get_course_details(
+    req: HttpRequest,
+    repository: web::Data<dyn CourseRepository>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    let course = repository.get_course_details(tutor_id, course_id).await?;
+    let etag = course_etag(&course);
+
+    // 클라이언트가 가진 버전이 최신이면 본문 없이 304
+    let not_modified = match req.get_header::<IfNoneMatch>() {
+        Some(IfNoneMatch::Any) => true,
+        Some(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(&etag.0)),
+        None => false,
+    };
+    if not_modified {
+        return Ok(HttpResponse::NotModified().insert_header(etag).finish());
+    }
+    Ok(HttpResponse::Ok().insert_header(etag).json(course))
+}
+
+/// update course by given tutor id and course id
+#[utoipa::path(
+    tag = COURSE,
+    request_body = UpdateCourse,
+    responses(
+        (status = 200, description = "course updated successfully", body = Course,
+            headers(("ETag" = String, description = "new version of the course"))),
+        (status = 400, description = "invalid course fields or If-Match header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "course belongs to another tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 412, description = "course has been modified since the given ETag", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 428, description = "missing If-Match header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course of tutor"),
+        ("If-Match" = String, Header, description = "ETag of the course being modified, or *"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[route("/{tutor_id}/{course_id}", method = "PUT", method = "PATCH")]
+#[instrument(skip_all)]
+pub async fn update_course_details(
+    req: HttpRequest,
+    tutor: AuthenticatedTutor,
+    repository: web::Data<dyn CourseRepository>,
+    Validated(update_course): Validated<web::Json<UpdateCourse>>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    tutor.ensure_owner(tutor_id)?;
+    let expected = version_match(&req)?;
+    repository
+        .update_course_details(tutor_id, course_id, update_course.try_into()?, &expected)
+        .await
+        .map(|course| {
+            HttpResponse::Ok()
+                .insert_header(course_etag(&course))
+                .json(course)
+        })
+}
+
+/// delete course by given tutor id and course id
+#[utoipa::path(
+    tag = COURSE,
+    responses(
+        (status = 200, description = "course deleted successfully", body = String),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "course belongs to another tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 400, description = "invalid If-Match header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 412, description = "course has been modified since the given ETag", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 428, description = "missing If-Match header", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course of tutor"),
+        ("If-Match" = String, Header, description = "ETag of the course being deleted, or *"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/{tutor_id}/{course_id}")]
+#[instrument(skip_all)]
+pub async fn delete_course(
+    req: HttpRequest,
+    tutor: AuthenticatedTutor,
+    repository: web::Data<dyn CourseRepository>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    tutor.ensure_owner(tutor_id)?;
+    let expected = version_match(&req)?;
+    repository
+        .delete_course(tutor_id, course_id, &expected)
+        .await
+        .map(|resp| HttpResponse::Ok().json(resp))
+}
+
+/// restore a deleted course before it is purged
+#[utoipa::path(
+    tag = COURSE,
+    responses(
+        (status = 200, description = "course restored successfully", body = Course,
+            headers(("ETag" = String, description = "new version of the course"))),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "course belongs to another tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "deleted course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course of tutor"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/{tutor_id}/{course_id}/restore")]
+#[instrument(skip_all)]
+pub async fn restore_course(
+    tutor: AuthenticatedTutor,
+    repository: web::Data<dyn CourseRepository>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    tutor.ensure_owner(tutor_id)?;
+    repository
+        .restore_course(tutor_id, course_id)
+        .await
+        .map(|course| {
+            HttpResponse::Ok()
+                .insert_header(course_etag(&course))
+                .json(course)
+        })
+}
@@ -0,0 +1,207 @@
+//! 서비스 상태 확인: 프로세스가 살아 있는지(liveness)와
+//! 요청을 처리할 수 있는지(readiness, 의존하는 저장소까지 확인)를 구분해 알려준다.
+
+use actix_web::{get, rt::time::timeout, web, HttpResponse};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+
+const HEALTH: &str = "health";
+
+/// 구성 요소 하나를 확인하는 데 기다리는 최대 시간
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// readiness에서 확인할 의존 구성 요소 (DB, 파일 저장소 등)
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    async fn check(&self) -> Result<(), String>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ComponentHealth {
+    pub status: HealthStatus,
+    pub latency_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+/// readiness에서 확인할 구성 요소 목록
+#[derive(Default)]
+pub struct Readiness {
+    checks: Vec<(&'static str, Arc<dyn HealthCheck>)>,
+    shutting_down: AtomicBool,
+}
+
+impl Readiness {
+    pub fn new() -> Self {
+        Readiness::default()
+    }
+
+    pub fn with(mut self, name: &'static str, check: Arc<dyn HealthCheck>) -> Self {
+        self.checks.push((name, check));
+        self
+    }
+
+    /// 종료를 시작했음을 알린다. 이후 readiness는 항상 `Down`
+    pub fn begin_shutdown(&self) {
+        self.shutting_down.store(true, Ordering::SeqCst);
+    }
+
+    /// 모든 구성 요소를 확인한다. 하나라도 실패하거나 시간 안에 응답하지 않으면 `Down`
+    pub async fn report(&self) -> HealthReport {
+        let mut components = BTreeMap::new();
+        if self.shutting_down.load(Ordering::SeqCst) {
+            components.insert(
+                "server".to_string(),
+                ComponentHealth {
+                    status: HealthStatus::Down,
+                    latency_ms: 0,
+                    error: Some("shutting down".into()),
+                },
+            );
+        }
+        for (name, check) in &self.checks {
+            let started = Instant::now();
+            let result = match timeout(CHECK_TIMEOUT, check.check()).await {
+                Ok(result) => result,
+                Err(_) => Err(format!("timed out after {:?}", CHECK_TIMEOUT)),
+            };
+            let latency_ms = started.elapsed().as_millis() as u64;
+
+            let component = match result {
+                Ok(()) => ComponentHealth {
+                    status: HealthStatus::Up,
+                    latency_ms,
+                    error: None,
+                },
+                Err(error) => {
+                    tracing::warn!(component = name, error = %error, "Health check failed");
+                    ComponentHealth {
+                        status: HealthStatus::Down,
+                        latency_ms,
+                        error: Some(error),
+                    }
+                }
+            };
+            components.insert(name.to_string(), component);
+        }
+
+        let status = if components
+            .values()
+            .all(|component| component.status == HealthStatus::Up)
+        {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+        HealthReport { status, components }
+    }
+}
+
+/// liveness probe: 프로세스가 요청에 응답하는지만 확인
+#[utoipa::path(
+    tag = HEALTH,
+    responses(
+        (status = 200, description = "process is running", body = HealthReport),
+    )
+)]
+#[get("/health/live")]
+pub async fn liveness() -> HttpResponse {
+    HttpResponse::Ok().json(HealthReport {
+        status: HealthStatus::Up,
+        components: BTreeMap::new(),
+    })
+}
+
+/// readiness probe: 의존 구성 요소별 상태와 응답 시간
+#[utoipa::path(
+    tag = HEALTH,
+    responses(
+        (status = 200, description = "every dependency is healthy", body = HealthReport),
+        (status = 503, description = "at least one dependency is unhealthy", body = HealthReport),
+    )
+)]
+#[get("/health/ready")]
+pub async fn readiness(readiness: web::Data<Readiness>) -> HttpResponse {
+    let report = readiness.report().await;
+    match report.status {
+        HealthStatus::Up => HttpResponse::Ok().json(report),
+        HealthStatus::Down => HttpResponse::ServiceUnavailable().json(report),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test, App};
+
+    struct StaticCheck(Result<(), String>);
+
+    #[async_trait]
+    impl HealthCheck for StaticCheck {
+        async fn check(&self) -> Result<(), String> {
+            self.0.clone()
+        }
+    }
+
+    #[actix_rt::test]
+    async fn readiness_reports_each_component() {
+        let checks = Readiness::new()
+            .with("database", Arc::new(StaticCheck(Ok(()))))
+            .with("storage", Arc::new(StaticCheck(Err("disk is gone".into()))));
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(checks))
+                .service(liveness)
+                .service(readiness),
+        )
+        .await;
+
+        let req = test::TestRequest::get().uri("/health/live").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::get().uri("/health/ready").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let report: HealthReport = test::read_body_json(resp).await;
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(report.components["database"].status, HealthStatus::Up);
+        assert_eq!(report.components["storage"].status, HealthStatus::Down);
+        assert_eq!(
+            report.components["storage"].error.as_deref(),
+            Some("disk is gone")
+        );
+    }
+
+    #[actix_rt::test]
+    async fn readiness_fails_after_shutdown_begins() {
+        let checks = Readiness::new().with("database", Arc::new(StaticCheck(Ok(()))));
+        assert_eq!(checks.report().await.status, HealthStatus::Up);
+
+        checks.begin_shutdown();
+        let report = checks.report().await;
+        assert_eq!(report.status, HealthStatus::Down);
+        assert_eq!(report.components["server"].status, HealthStatus::Down);
+        assert_eq!(report.components["database"].status, HealthStatus::Up);
+    }
+}
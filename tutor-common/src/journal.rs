@@ -0,0 +1,158 @@
+//! 메모리 저장소의 파일 영속화: JSON 스냅샷 + write-ahead 로그.
+//!
+//! 모든 변경은 먼저 로그 파일에 한 줄(JSON)로 기록한 뒤 메모리에 반영한다.
+//! 시작할 때는 스냅샷을 읽고 그 뒤의 로그를 다시 적용하며, 로그가 일정 개수 쌓이면
+//! 현재 상태를 새 스냅샷으로 저장하고 로그를 비운다.
+//! 로그 항목은 몇 번을 다시 적용해도 결과가 같으므로, 스냅샷 교체 후 로그를 비우기 전에
+//! 종료되어도 복구 결과는 달라지지 않는다.
+
+use super::models::Course;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+const SNAPSHOT_FILE: &str = "courses.snapshot.json";
+const LOG_FILE: &str = "courses.log";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(crate) enum JournalEntry {
+    /// course 생성 또는 수정 (변경 후 전체 내용을 기록)
+    Upsert {
+        course: Box<Course>,
+    },
+    Delete {
+        tutor_id: i32,
+        course_id: i32,
+    },
+}
+
+pub(crate) struct Journal {
+    dir: PathBuf,
+    log: File,
+    compact_every: usize,
+    entries_since_snapshot: usize,
+}
+
+impl Journal {
+    /// 스냅샷과 다시 적용할 로그 항목을 읽어 온다.
+    ///
+    /// 마지막 줄이 개행 없이 잘려 있으면 (기록 도중 종료) 그 부분을 버리고 파일을 잘라낸다.
+    /// 중간 줄이 손상된 경우에는 복구하지 않고 에러를 돌려준다.
+    pub(crate) fn open<S: DeserializeOwned + Default>(
+        dir: &Path,
+        compact_every: usize,
+    ) -> io::Result<(Self, S, Vec<JournalEntry>)> {
+        fs::create_dir_all(dir)?;
+
+        let snapshot = match fs::read(dir.join(SNAPSHOT_FILE)) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => S::default(),
+            Err(error) => return Err(error),
+        };
+
+        let mut log = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(dir.join(LOG_FILE))?;
+        let mut bytes = vec![];
+        log.read_to_end(&mut bytes)?;
+
+        let complete = bytes
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |newline| newline + 1);
+        if complete < bytes.len() {
+            tracing::warn!(
+                bytes = bytes.len() - complete,
+                "Discarding truncated journal entry"
+            );
+            log.set_len(complete as u64)?;
+            log.sync_data()?;
+        }
+
+        let entries = bytes[..complete]
+            .split(|&byte| byte == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(serde_json::from_slice)
+            .collect::<Result<Vec<JournalEntry>, _>>()?;
+
+        let journal = Journal {
+            dir: dir.to_path_buf(),
+            log,
+            compact_every,
+            entries_since_snapshot: entries.len(),
+        };
+        Ok((journal, snapshot, entries))
+    }
+
+    pub(crate) fn append(&mut self, entry: &JournalEntry) -> io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+
+        // 기록 도중 실패하면 쓰다 만 조각을 잘라낸다.
+        // 남겨 두면 다음 항목이 그 뒤에 이어 붙어 로그 중간에 손상된 줄이 생기고 다시 열 수 없게 됨
+        let len = self.log.metadata()?.len();
+        if let Err(error) = self
+            .log
+            .write_all(&line)
+            .and_then(|()| self.log.sync_data())
+        {
+            if let Err(truncate_error) = self.log.set_len(len) {
+                tracing::error!(
+                    error = %truncate_error,
+                    "Failed to remove partially written journal entry"
+                );
+            }
+            return Err(error);
+        }
+        self.entries_since_snapshot += 1;
+        Ok(())
+    }
+
+    pub(crate) fn should_compact(&self) -> bool {
+        self.entries_since_snapshot >= self.compact_every
+    }
+
+    /// 저장 디렉터리와 로그 파일에 계속 기록할 수 있는지 확인한다.
+    pub(crate) fn check(&self) -> io::Result<()> {
+        for metadata in [fs::metadata(&self.dir)?, self.log.metadata()?] {
+            if metadata.permissions().readonly() {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("{} is read-only", self.dir.display()),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// 현재 상태를 새 스냅샷으로 저장하고 로그를 비운다.
+    pub(crate) fn compact<S: Serialize>(&mut self, snapshot: &S) -> io::Result<()> {
+        let tmp_path = self.dir.join(format!("{}.tmp", SNAPSHOT_FILE));
+        let mut tmp = File::create(&tmp_path)?;
+        tmp.write_all(&serde_json::to_vec(snapshot)?)?;
+        tmp.sync_all()?;
+        fs::rename(&tmp_path, self.dir.join(SNAPSHOT_FILE))?;
+        // 로그를 비우기 전에 새 스냅샷으로 바꾼 것이 디스크에 남도록 디렉터리도 동기화
+        sync_dir(&self.dir)?;
+
+        self.log.set_len(0)?;
+        self.log.sync_data()?;
+        self.entries_since_snapshot = 0;
+        Ok(())
+    }
+}
+
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+/// 디렉터리를 열어 동기화할 수 없는 플랫폼에서는 rename만으로 만족
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
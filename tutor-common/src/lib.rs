@@ -0,0 +1,15 @@
+pub mod auth;
+pub mod errors;
+pub mod handlers;
+pub mod health;
+mod journal;
+pub mod memory;
+pub mod metrics;
+pub mod models;
+pub mod repository;
+pub mod routes;
+pub mod settings;
+pub mod shutdown;
+pub mod telemetry;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
@@ -0,0 +1,750 @@
+use super::errors::EzyTutorError;
+use super::health::HealthCheck;
+use super::journal::{Journal, JournalEntry};
+use super::models::{
+    Course, CourseQuery, CourseSearchHit, CourseSearchQuery, CourseSort, CreateCourse, SortOrder,
+    UpdateCourse, VersionMatch,
+};
+use super::repository::CourseRepository;
+use super::settings::RetentionSettings;
+use actix_web::rt::{spawn, task::JoinHandle, time::interval};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+#[derive(Default, Serialize, Deserialize)]
+struct CourseStore {
+    courses: Vec<Course>,
+    /// tutor별로 마지막에 발급한 course_id. 삭제해도 줄지 않으므로 id가 재사용되지 않음
+    last_course_ids: HashMap<i32, i32>,
+    #[serde(skip)]
+    journal: Option<Journal>,
+}
+
+impl CourseStore {
+    fn apply(&mut self, entry: JournalEntry) {
+        match entry {
+            JournalEntry::Upsert { course } => {
+                let last_course_id = self.last_course_ids.entry(course.tutor_id).or_insert(0);
+                *last_course_id = (*last_course_id).max(course.course_id);
+
+                match self.courses.iter_mut().find(|existing| {
+                    existing.tutor_id == course.tutor_id && existing.course_id == course.course_id
+                }) {
+                    Some(existing) => *existing = *course,
+                    None => self.courses.push(*course),
+                }
+            }
+            JournalEntry::Delete {
+                tutor_id,
+                course_id,
+            } => self
+                .courses
+                .retain(|course| !(course.tutor_id == tutor_id && course.course_id == course_id)),
+        }
+    }
+
+    /// 삭제되지 않은 course
+    fn find_active(&self, tutor_id: i32, course_id: i32) -> Result<&Course, EzyTutorError> {
+        self.courses
+            .iter()
+            .find(|course| {
+                course.tutor_id == tutor_id
+                    && course.course_id == course_id
+                    && course.deleted_at.is_none()
+            })
+            .ok_or_else(|| EzyTutorError::NotFound("Course is not found".into()))
+    }
+
+    /// 파일 모드에서는 로그에 먼저 기록한 뒤 메모리에 반영한다.
+    fn commit(&mut self, entry: JournalEntry) -> Result<(), EzyTutorError> {
+        if let Some(journal) = self.journal.as_mut() {
+            journal
+                .append(&entry)
+                .map_err(|error| EzyTutorError::ActixError(error.to_string()))?;
+        }
+        self.apply(entry);
+
+        if let Some(mut journal) = self.journal.take() {
+            if journal.should_compact() {
+                // 변경은 이미 로그에 남아 있으므로 스냅샷 저장 실패는 요청 실패로 보지 않음
+                if let Err(error) = journal.compact(&*self) {
+                    tracing::error!(error = %error, "Journal compaction failed");
+                }
+            }
+            self.journal = Some(journal);
+        }
+        Ok(())
+    }
+}
+
+/// 프로세스 메모리에 course를 보관하는 저장소
+#[derive(Default)]
+pub struct InMemoryCourseRepository {
+    store: Mutex<CourseStore>,
+}
+
+impl InMemoryCourseRepository {
+    pub fn new() -> Self {
+        InMemoryCourseRepository::default()
+    }
+
+    /// `dir`의 스냅샷과 로그로 상태를 복구하고, 이후 변경을 파일에도 기록하는 저장소를 연다.
+    ///
+    /// 로그 항목이 `compact_every`개 쌓일 때마다 새 스냅샷으로 압축한다.
+    pub fn open(dir: impl AsRef<Path>, compact_every: usize) -> io::Result<Self> {
+        let (journal, mut store, entries) =
+            Journal::open::<CourseStore>(dir.as_ref(), compact_every.max(1))?;
+        for entry in entries {
+            store.apply(entry);
+        }
+        store.journal = Some(journal);
+
+        Ok(InMemoryCourseRepository {
+            store: Mutex::new(store),
+        })
+    }
+
+    /// 파일 모드에서 현재 상태를 스냅샷으로 저장하고 로그를 비운다. (종료 전 호출)
+    ///
+    /// 로그는 변경마다 디스크에 기록되므로 데이터 보존에 꼭 필요하지는 않지만,
+    /// 다음 시작 때 다시 적용할 로그가 없어진다.
+    pub fn flush(&self) -> io::Result<()> {
+        let mut store = self.store.lock().unwrap();
+        match store.journal.take() {
+            Some(mut journal) => {
+                let result = journal.compact(&*store);
+                store.journal = Some(journal);
+                result
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// `retention_days`일보다 오래전에 삭제된 course를 영구 삭제하고 삭제한 수를 돌려준다.
+    pub fn purge_deleted_courses(&self, retention_days: i32) -> Result<usize, EzyTutorError> {
+        let mut store = self.store.lock().unwrap();
+        let cutoff = Utc::now().naive_utc() - Duration::days(retention_days.into());
+
+        let expired = store
+            .courses
+            .iter()
+            .filter(|course| {
+                course
+                    .deleted_at
+                    .is_some_and(|deleted_at| deleted_at < cutoff)
+            })
+            .map(|course| (course.tutor_id, course.course_id))
+            .collect::<Vec<_>>();
+        for (tutor_id, course_id) in &expired {
+            store.commit(JournalEntry::Delete {
+                tutor_id: *tutor_id,
+                course_id: *course_id,
+            })?;
+        }
+        Ok(expired.len())
+    }
+
+    /// `purge_interval_secs`마다 보관 기간이 지난 course를 영구 삭제한다. 첫 실행은 바로 시작
+    pub fn spawn_purge(self: &Arc<Self>, settings: &RetentionSettings) -> JoinHandle<()> {
+        let repository = self.clone();
+        let retention_days = settings.deleted_course_days;
+        let period = std::time::Duration::from_secs(settings.purge_interval_secs);
+
+        spawn(async move {
+            let mut ticks = interval(period);
+            loop {
+                ticks.tick().await;
+                match repository.purge_deleted_courses(retention_days) {
+                    Ok(0) => {}
+                    Ok(purged) => {
+                        tracing::info!(purged, retention_days, "Purged deleted courses")
+                    }
+                    // 다음 주기에 다시 시도
+                    Err(error) => tracing::warn!(error = %error, "Failed to purge deleted courses"),
+                }
+            }
+        })
+    }
+
+    /// 삭제되지 않은 course 수 (보관 중인 삭제된 course는 제외)
+    pub fn course_count(&self) -> usize {
+        let store = self.store.lock().unwrap();
+        store
+            .courses
+            .iter()
+            .filter(|course| course.deleted_at.is_none())
+            .count()
+    }
+
+    /// 삭제되지 않은 course를 하나 이상 가진 tutor 수
+    pub fn tutor_count(&self) -> usize {
+        let store = self.store.lock().unwrap();
+        store
+            .courses
+            .iter()
+            .filter(|course| course.deleted_at.is_none())
+            .map(|course| course.tutor_id)
+            .collect::<HashSet<_>>()
+            .len()
+    }
+}
+
+/// 파일 모드에서는 저장 디렉터리에 계속 기록할 수 있어야 준비된 상태
+#[async_trait]
+impl HealthCheck for InMemoryCourseRepository {
+    async fn check(&self) -> Result<(), String> {
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| "course store lock is poisoned".to_string())?;
+        match &store.journal {
+            Some(journal) => journal.check().map_err(|error| error.to_string()),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl CourseRepository for InMemoryCourseRepository {
+    async fn get_courses_for_tutor(
+        &self,
+        tutor_id: i32,
+        query: &CourseQuery,
+    ) -> Result<(Vec<Course>, i64), EzyTutorError> {
+        let store = self.store.lock().unwrap();
+        let courses = &store.courses;
+        let name = query.name.as_ref().map(|name| name.to_lowercase());
+
+        let mut matched = courses
+            .iter()
+            .filter(|course| course.tutor_id == tutor_id)
+            .filter(|course| query.include_deleted() || course.deleted_at.is_none())
+            .filter(|course| match &name {
+                Some(name) => course.course_name.to_lowercase().contains(name),
+                None => true,
+            })
+            .collect::<Vec<&Course>>();
+
+        matched.sort_by(|a, b| {
+            let ordering = match query.sort() {
+                CourseSort::CourseId => a.course_id.cmp(&b.course_id),
+                CourseSort::PostedTime => a.posted_time.cmp(&b.posted_time),
+                CourseSort::CourseName => a.course_name.cmp(&b.course_name),
+            }
+            .then(a.course_id.cmp(&b.course_id));
+            match query.order() {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            }
+        });
+
+        let total = matched.len() as i64;
+
+        let page = matched
+            .into_iter()
+            .skip(query.offset() as usize)
+            .take(query.limit() as usize)
+            .cloned()
+            .collect::<Vec<Course>>();
+
+        Ok((page, total))
+    }
+
+    async fn search_courses(
+        &self,
+        query: &CourseSearchQuery,
+    ) -> Result<(Vec<CourseSearchHit>, i64), EzyTutorError> {
+        let store = self.store.lock().unwrap();
+        let courses = &store.courses;
+        let term = query.q.trim();
+
+        let mut hits = courses
+            .iter()
+            .filter(|course| course.deleted_at.is_none())
+            .filter_map(|course| {
+                let name_match = find_ignore_case(&course.course_name, term);
+                let description_match = course
+                    .course_description
+                    .as_deref()
+                    .and_then(|description| find_ignore_case(description, term));
+
+                // 이름에서 찾은 경우 설명에서 찾은 경우보다 관련도를 높게 매김
+                let (rank, snippet) = match (name_match, description_match) {
+                    (Some(range), Some(_)) => (1.5, highlight(&course.course_name, range)),
+                    (Some(range), None) => (1.0, highlight(&course.course_name, range)),
+                    (None, Some(range)) => (
+                        0.5,
+                        highlight(course.course_description.as_deref().unwrap(), range),
+                    ),
+                    (None, None) => return None,
+                };
+
+                Some(CourseSearchHit {
+                    course: course.clone(),
+                    rank,
+                    snippet,
+                })
+            })
+            .collect::<Vec<CourseSearchHit>>();
+
+        hits.sort_by(|a, b| {
+            b.rank
+                .total_cmp(&a.rank)
+                .then(a.course.course_id.cmp(&b.course.course_id))
+        });
+
+        let total = hits.len() as i64;
+        let page = hits
+            .into_iter()
+            .skip(query.offset() as usize)
+            .take(query.limit() as usize)
+            .collect::<Vec<CourseSearchHit>>();
+
+        Ok((page, total))
+    }
+
+    async fn get_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+    ) -> Result<Course, EzyTutorError> {
+        self.store
+            .lock()
+            .unwrap()
+            .find_active(tutor_id, course_id)
+            .cloned()
+    }
+
+    async fn post_new_course(&self, new_course: CreateCourse) -> Result<Course, EzyTutorError> {
+        // id 발급과 저장을 같은 잠금 안에서 처리해 동시 요청에도 id가 겹치지 않음
+        let mut store = self.store.lock().unwrap();
+
+        let course_id = store
+            .last_course_ids
+            .get(&new_course.tutor_id)
+            .copied()
+            .unwrap_or(0)
+            + 1;
+
+        let course = Course {
+            course_id,
+            tutor_id: new_course.tutor_id,
+            course_name: new_course.course_name,
+            course_description: new_course.course_description,
+            course_format: new_course.course_format,
+            course_structure: new_course.course_structure,
+            course_duration: new_course.course_duration,
+            course_price: new_course.course_price,
+            course_language: new_course.course_language,
+            course_level: new_course.course_level,
+            course_capacity: new_course.course_capacity,
+            posted_time: Some(Utc::now().naive_utc()),
+            average_rating: None,
+            review_count: 0,
+            deleted_at: None,
+            version: 1,
+        };
+
+        store.commit(JournalEntry::Upsert {
+            course: Box::new(course.clone()),
+        })?;
+        Ok(course)
+    }
+
+    async fn update_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        update_course: UpdateCourse,
+        expected: &VersionMatch,
+    ) -> Result<Course, EzyTutorError> {
+        let mut store = self.store.lock().unwrap();
+
+        let mut course = store.find_active(tutor_id, course_id)?.clone();
+        ensure_version(&course, expected)?;
+
+        if let Some(course_name) = update_course.course_name {
+            course.course_name = course_name;
+        }
+        if update_course.course_description.is_some() {
+            course.course_description = update_course.course_description;
+        }
+        if update_course.course_format.is_some() {
+            course.course_format = update_course.course_format;
+        }
+        if update_course.course_structure.is_some() {
+            course.course_structure = update_course.course_structure;
+        }
+        if update_course.course_duration.is_some() {
+            course.course_duration = update_course.course_duration;
+        }
+        if update_course.course_price.is_some() {
+            course.course_price = update_course.course_price;
+        }
+        if update_course.course_language.is_some() {
+            course.course_language = update_course.course_language;
+        }
+        if update_course.course_level.is_some() {
+            course.course_level = update_course.course_level;
+        }
+        if update_course.course_capacity.is_some() {
+            course.course_capacity = update_course.course_capacity;
+        }
+        course.version += 1;
+
+        store.commit(JournalEntry::Upsert {
+            course: Box::new(course.clone()),
+        })?;
+        Ok(course)
+    }
+
+    async fn delete_course(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        expected: &VersionMatch,
+    ) -> Result<String, EzyTutorError> {
+        let mut store = self.store.lock().unwrap();
+
+        let mut course = store.find_active(tutor_id, course_id)?.clone();
+        ensure_version(&course, expected)?;
+
+        // 삭제 시각만 기록하고, 보관 기간이 지나면 purge_deleted_courses가 영구 삭제
+        course.deleted_at = Some(Utc::now().naive_utc());
+        course.version += 1;
+        store.commit(JournalEntry::Upsert {
+            course: Box::new(course),
+        })?;
+        Ok("Deleted 1 record".to_string())
+    }
+
+    async fn restore_course(&self, tutor_id: i32, course_id: i32) -> Result<Course, EzyTutorError> {
+        let mut store = self.store.lock().unwrap();
+
+        let mut course = store
+            .courses
+            .iter()
+            .find(|course| {
+                course.tutor_id == tutor_id
+                    && course.course_id == course_id
+                    && course.deleted_at.is_some()
+            })
+            .cloned()
+            .ok_or_else(|| EzyTutorError::NotFound("Deleted course is not found".into()))?;
+
+        course.deleted_at = None;
+        course.version += 1;
+        store.commit(JournalEntry::Upsert {
+            course: Box::new(course.clone()),
+        })?;
+        Ok(course)
+    }
+}
+
+fn ensure_version(course: &Course, expected: &VersionMatch) -> Result<(), EzyTutorError> {
+    if expected.matches(course.version) {
+        Ok(())
+    } else {
+        Err(EzyTutorError::PreconditionFailed(
+            "Course has been modified by another request".into(),
+        ))
+    }
+}
+
+/// 대소문자 구분 없이 `term`이 처음 나타나는 byte 범위를 찾는다.
+fn find_ignore_case(text: &str, term: &str) -> Option<(usize, usize)> {
+    let term = term.to_lowercase();
+    let term_chars = term.chars().count();
+    if term_chars == 0 {
+        return None;
+    }
+
+    text.char_indices().find_map(|(start, _)| {
+        let rest = &text[start..];
+        let len = rest
+            .chars()
+            .take(term_chars)
+            .map(char::len_utf8)
+            .sum::<usize>();
+        (rest[..len].to_lowercase() == term).then_some((start, start + len))
+    })
+}
+
+fn highlight(text: &str, (start, end): (usize, usize)) -> String {
+    format!(
+        "{}<b>{}</b>{}",
+        &text[..start],
+        &text[start..end],
+        &text[end..]
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::course_api_suite;
+    use std::sync::Arc;
+
+    #[actix_rt::test]
+    async fn course_api_in_memory() {
+        course_api_suite(Arc::new(InMemoryCourseRepository::new()), 1).await;
+    }
+
+    fn new_course(tutor_id: i32, course_name: String) -> CreateCourse {
+        CreateCourse {
+            tutor_id,
+            course_name,
+            course_description: None,
+            course_format: None,
+            course_structure: None,
+            course_duration: None,
+            course_price: None,
+            course_language: None,
+            course_level: None,
+            course_capacity: None,
+        }
+    }
+
+    #[test]
+    fn concurrent_posts_get_unique_course_ids() {
+        const THREADS: i32 = 16;
+        const POSTS_PER_THREAD: i32 = 25;
+
+        let repository = Arc::new(InMemoryCourseRepository::new());
+        let handles = (0..THREADS)
+            .map(|thread| {
+                let repository = repository.clone();
+                std::thread::spawn(move || {
+                    actix_rt::System::new().block_on(async move {
+                        let mut course_ids = vec![];
+                        for post in 0..POSTS_PER_THREAD {
+                            let course = repository
+                                .post_new_course(new_course(
+                                    1,
+                                    format!("Course {}-{}", thread, post),
+                                ))
+                                .await
+                                .unwrap();
+                            course_ids.push(course.course_id);
+                        }
+                        course_ids
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut course_ids = handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        course_ids.sort();
+        course_ids.dedup();
+
+        assert_eq!(
+            course_ids,
+            (1..=THREADS * POSTS_PER_THREAD).collect::<Vec<_>>()
+        );
+    }
+
+    #[actix_rt::test]
+    async fn deleted_course_ids_are_not_reused() {
+        let repository = InMemoryCourseRepository::new();
+        for course_name in ["First", "Second"] {
+            repository
+                .post_new_course(new_course(1, course_name.into()))
+                .await
+                .unwrap();
+        }
+        repository
+            .delete_course(1, 2, &VersionMatch::Any)
+            .await
+            .unwrap();
+
+        let course = repository
+            .post_new_course(new_course(1, "Third".into()))
+            .await
+            .unwrap();
+        assert_eq!(course.course_id, 3);
+
+        // tutor마다 별도의 sequence를 사용
+        let course = repository
+            .post_new_course(new_course(2, "Other tutor".into()))
+            .await
+            .unwrap();
+        assert_eq!(course.course_id, 1);
+    }
+
+    #[actix_rt::test]
+    async fn purge_removes_only_expired_deleted_courses() {
+        let repository = InMemoryCourseRepository::new();
+        for course_name in ["Kept", "Deleted"] {
+            repository
+                .post_new_course(new_course(1, course_name.into()))
+                .await
+                .unwrap();
+        }
+        repository
+            .post_new_course(new_course(2, "Deleted of other tutor".into()))
+            .await
+            .unwrap();
+        for (tutor_id, course_id) in [(1, 2), (2, 1)] {
+            repository
+                .delete_course(tutor_id, course_id, &VersionMatch::Any)
+                .await
+                .unwrap();
+        }
+
+        // 보관 중인 course는 개수에 포함하지 않음
+        assert_eq!(repository.course_count(), 1);
+        assert_eq!(repository.tutor_count(), 1);
+
+        // 보관 기간이 남은 course는 그대로 두고 복구할 수 있음
+        assert_eq!(repository.purge_deleted_courses(30).unwrap(), 0);
+        let restored = repository.restore_course(1, 2).await.unwrap();
+        assert_eq!(restored.version, 3);
+        repository
+            .delete_course(1, 2, &VersionMatch::Any)
+            .await
+            .unwrap();
+
+        assert_eq!(repository.purge_deleted_courses(0).unwrap(), 2);
+        assert_eq!(repository.course_count(), 1);
+        assert!(repository.restore_course(1, 2).await.is_err());
+    }
+
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "ezytutors-{}-{}",
+            name,
+            Utc::now().timestamp_nanos_opt().unwrap()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    async fn course_names(repository: &InMemoryCourseRepository, tutor_id: i32) -> Vec<String> {
+        let (courses, _) = repository
+            .get_courses_for_tutor(tutor_id, &CourseQuery::default())
+            .await
+            .unwrap();
+        courses
+            .into_iter()
+            .map(|course| course.course_name)
+            .collect()
+    }
+
+    #[actix_rt::test]
+    async fn file_backed_repository_survives_reopen_and_compaction() {
+        let dir = temp_dir("reopen");
+
+        let repository = InMemoryCourseRepository::open(&dir, 3).unwrap();
+        for course_name in ["First", "Second", "Third", "Fourth"] {
+            repository
+                .post_new_course(new_course(1, course_name.into()))
+                .await
+                .unwrap();
+        }
+        repository
+            .update_course_details(
+                1,
+                1,
+                UpdateCourse {
+                    course_name: Some("First (renamed)".into()),
+                    ..UpdateCourse::default()
+                },
+                &VersionMatch::Versions(vec![1]),
+            )
+            .await
+            .unwrap();
+        repository
+            .delete_course(1, 4, &VersionMatch::Any)
+            .await
+            .unwrap();
+        drop(repository);
+
+        // 변경 6번을 3개마다 압축했으므로 상태는 모두 스냅샷에 있고 로그는 비어 있음
+        assert!(dir.join("courses.snapshot.json").exists());
+        let log = std::fs::read_to_string(dir.join("courses.log")).unwrap();
+        assert_eq!(log.lines().count(), 0);
+
+        let repository = InMemoryCourseRepository::open(&dir, 3).unwrap();
+        assert_eq!(
+            course_names(&repository, 1).await,
+            vec!["First (renamed)", "Second", "Third"]
+        );
+        let renamed = repository.get_course_details(1, 1).await.unwrap();
+        assert_eq!(renamed.version, 2);
+
+        // 삭제된 id는 다시 열어도 재사용하지 않음
+        let course = repository
+            .post_new_course(new_course(1, "Fifth".into()))
+            .await
+            .unwrap();
+        assert_eq!(course.course_id, 5);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn flush_moves_logged_changes_into_snapshot() {
+        let dir = temp_dir("flush");
+
+        let repository = InMemoryCourseRepository::open(&dir, 100).unwrap();
+        repository
+            .post_new_course(new_course(1, "Logged".into()))
+            .await
+            .unwrap();
+        repository.flush().unwrap();
+        drop(repository);
+
+        let log = std::fs::read_to_string(dir.join("courses.log")).unwrap();
+        assert!(log.is_empty());
+        let repository = InMemoryCourseRepository::open(&dir, 100).unwrap();
+        assert_eq!(course_names(&repository, 1).await, vec!["Logged"]);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[actix_rt::test]
+    async fn file_backed_repository_discards_truncated_log_entry() {
+        let dir = temp_dir("truncated");
+
+        let repository = InMemoryCourseRepository::open(&dir, 100).unwrap();
+        repository
+            .post_new_course(new_course(1, "Durable".into()))
+            .await
+            .unwrap();
+        drop(repository);
+
+        // 기록 도중 종료되어 마지막 항목이 잘린 상황
+        let mut log = std::fs::OpenOptions::new()
+            .append(true)
+            .open(dir.join("courses.log"))
+            .unwrap();
+        std::io::Write::write_all(&mut log, br#"{"op":"upsert","course":{"course_id":2,"#).unwrap();
+        drop(log);
+
+        let repository = InMemoryCourseRepository::open(&dir, 100).unwrap();
+        assert_eq!(course_names(&repository, 1).await, vec!["Durable"]);
+
+        // 잘린 부분을 정리했으므로 이후 기록도 다시 읽을 수 있음
+        repository
+            .post_new_course(new_course(1, "After recovery".into()))
+            .await
+            .unwrap();
+        drop(repository);
+
+        let repository = InMemoryCourseRepository::open(&dir, 100).unwrap();
+        assert_eq!(
+            course_names(&repository, 1).await,
+            vec!["Durable", "After recovery"]
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+//! Prometheus 메트릭: 라우트 패턴별 요청 수/상태 코드/응답 시간과 처리 중인 요청 수.
+//!
+//! 서비스마다 다른 값(DB 풀 사용량, course 수 등)은 `FnGauge`로 등록하면
+//! `/metrics`를 읽을 때마다 현재 값을 계산한다.
+
+use actix_web::{
+    body::MessageBody,
+    dev::{ServiceRequest, ServiceResponse},
+    get,
+    middleware::Next,
+    web, HttpResponse,
+};
+use prometheus::{
+    core::{Collector, Desc},
+    proto::MetricFamily,
+    HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use std::time::Instant;
+
+const METRICS: &str = "metrics";
+
+/// 매칭되는 라우트가 없는 요청은 경로 대신 이 이름으로 묶는다 (label 수가 끝없이 늘지 않도록)
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+pub struct Metrics {
+    registry: Registry,
+    requests: IntCounterVec,
+    latency: HistogramVec,
+    in_flight: IntGauge,
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Number of HTTP requests"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let latency = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency in seconds",
+            ),
+            &["method", "route"],
+        )
+        .unwrap();
+        let in_flight = IntGauge::new(
+            "http_requests_in_flight",
+            "Number of HTTP requests being processed",
+        )
+        .unwrap();
+
+        let metrics = Metrics {
+            registry: Registry::new(),
+            requests,
+            latency,
+            in_flight,
+        };
+        metrics.register(metrics.requests.clone());
+        metrics.register(metrics.latency.clone());
+        metrics.register(metrics.in_flight.clone());
+        metrics
+    }
+
+    /// 서비스별 메트릭을 추가한다. 이름이 겹치면 시작할 때 바로 알 수 있도록 panic
+    pub fn register(&self, collector: impl Collector + 'static) {
+        self.registry
+            .register(Box::new(collector))
+            .expect("metric names must be unique");
+    }
+
+    /// Prometheus text 형식으로 모든 메트릭을 내보낸다.
+    pub fn render(&self) -> String {
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .unwrap_or_else(|error| {
+                tracing::error!(error = %error, "Failed to encode metrics");
+                String::new()
+            })
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new()
+    }
+}
+
+/// 읽을 때마다 함수로 값을 계산하는 gauge
+pub struct FnGauge {
+    gauge: IntGauge,
+    value: Box<dyn Fn() -> i64 + Send + Sync>,
+}
+
+impl FnGauge {
+    pub fn new(name: &str, help: &str, value: impl Fn() -> i64 + Send + Sync + 'static) -> Self {
+        FnGauge {
+            gauge: IntGauge::new(name, help).unwrap(),
+            value: Box::new(value),
+        }
+    }
+}
+
+impl Collector for FnGauge {
+    fn desc(&self) -> Vec<&Desc> {
+        self.gauge.desc()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        self.gauge.set((self.value)());
+        self.gauge.collect()
+    }
+}
+
+/// 요청이 끝나면 (에러나 취소 포함) 처리 중인 요청 수를 줄인다.
+struct InFlight(IntGauge);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.dec();
+    }
+}
+
+/// 요청마다 라우트 패턴(예: `/courses/{tutor_id}`) 기준으로 수와 응답 시간을 기록하는 미들웨어.
+///
+/// 앱에 `web::Data<Metrics>`가 없으면 아무것도 기록하지 않는다.
+pub async fn track_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let metrics = match req.app_data::<web::Data<Metrics>>() {
+        Some(metrics) => metrics.clone(),
+        None => return next.call(req).await,
+    };
+    let method = req.method().to_string();
+    let route = req
+        .match_pattern()
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+
+    metrics.in_flight.inc();
+    let _in_flight = InFlight(metrics.in_flight.clone());
+    let started = Instant::now();
+
+    let res = next.call(req).await;
+    let status = match &res {
+        Ok(res) => res.status(),
+        Err(error) => error.as_response_error().status_code(),
+    };
+
+    metrics
+        .latency
+        .with_label_values(&[&method, &route])
+        .observe(started.elapsed().as_secs_f64());
+    metrics
+        .requests
+        .with_label_values(&[&method, &route, status.as_str()])
+        .inc();
+    res
+}
+
+/// Prometheus scrape endpoint
+#[utoipa::path(
+    tag = METRICS,
+    responses(
+        (status = 200, description = "metrics in Prometheus text format", body = String, content_type = "text/plain"),
+    )
+)]
+#[get("/metrics")]
+pub async fn metrics_handler(metrics: web::Data<Metrics>) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(prometheus::TEXT_FORMAT)
+        .body(metrics.render())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, middleware::from_fn, test, App};
+
+    #[actix_rt::test]
+    async fn requests_are_counted_per_route_pattern() {
+        let metrics = web::Data::new(Metrics::new());
+        metrics.register(FnGauge::new("courses", "Number of courses", || 7));
+        let app = test::init_service(
+            App::new()
+                .wrap(from_fn(track_requests))
+                .app_data(metrics.clone())
+                .route(
+                    "/courses/{tutor_id}",
+                    web::get().to(|| async { HttpResponse::Ok().finish() }),
+                )
+                .service(metrics_handler),
+        )
+        .await;
+
+        for uri in ["/courses/1", "/courses/2", "/missing/1"] {
+            let req = test::TestRequest::get().uri(uri).to_request();
+            test::call_service(&app, req).await;
+        }
+
+        let req = test::TestRequest::get().uri("/metrics").to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = String::from_utf8(test::read_body(resp).await.to_vec()).unwrap();
+        for line in [
+            r#"http_requests_total{method="GET",route="/courses/{tutor_id}",status="200"} 2"#,
+            r#"http_requests_total{method="GET",route="unmatched",status="404"} 1"#,
+            r#"http_request_duration_seconds_count{method="GET",route="/courses/{tutor_id}"} 2"#,
+            // /metrics 요청 자신
+            "http_requests_in_flight 1",
+            "courses 7",
+        ] {
+            assert!(body.contains(line), "{} is missing in\n{}", line, body);
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use super::errors::EzyTutorError;
+use actix_web::web;
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+#[derive(Serialize, Deserialize, ToSchema, Debug, Clone)]
+pub struct Course {
+    #[schema(example = 1)]
+    pub course_id: i32,
+    #[schema(example = 1)]
+    pub tutor_id: i32,
+    #[schema(example = "first course!")]
+    pub course_name: String,
+    pub course_description: Option<String>,
+    pub course_format: Option<String>,
+    pub course_structure: Option<String>,
+    pub course_duration: Option<String>,
+    pub course_price: Option<i32>,
+    pub course_language: Option<String>,
+    pub course_level: Option<String>,
+    /// 수강할 수 있는 최대 학생 수. 없으면 제한 없음
+    pub course_capacity: Option<i32>,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub posted_time: Option<NaiveDateTime>,
+    /// 평균 평점 (1~5, 소수점 둘째 자리까지). 리뷰가 없으면 없음
+    #[schema(example = 4.5)]
+    pub average_rating: Option<f64>,
+    /// 리뷰 수. 리뷰 기능이 없는 저장소에서는 항상 0
+    #[serde(default)]
+    pub review_count: i32,
+    /// 삭제된 시각. 삭제된 course를 함께 조회할 때만 값이 있음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub deleted_at: Option<NaiveDateTime>,
+    /// 수정할 때마다 1씩 증가. `ETag`와 `If-Match`에 사용
+    #[serde(default)]
+    #[schema(example = 1)]
+    pub version: i32,
+}
+
+/// 수정/삭제 요청의 `If-Match`로 받은 course 버전 조건
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionMatch {
+    /// `If-Match: *` (course가 있기만 하면 됨)
+    Any,
+    /// 현재 버전이 이 중 하나여야 함
+    Versions(Vec<i32>),
+}
+
+impl VersionMatch {
+    pub fn matches(&self, version: i32) -> bool {
+        match self {
+            VersionMatch::Any => true,
+            VersionMatch::Versions(versions) => versions.contains(&version),
+        }
+    }
+
+    /// SQL 조건에 바인딩할 버전 목록. `Any`이면 없음
+    pub fn versions(&self) -> Option<&[i32]> {
+        match self {
+            VersionMatch::Any => None,
+            VersionMatch::Versions(versions) => Some(versions),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct CreateCourse {
+    #[validate(range(min = 1, message = "1 이상의 값을 입력하세요"))]
+    #[schema(example = 1)]
+    pub tutor_id: i32,
+    #[validate(length(min = 1, max = 140, message = "1~140 글자로 입력하세요"))]
+    #[schema(example = "first course!")]
+    pub course_name: String,
+    #[validate(length(max = 2000, message = "2000 글자 이하로 입력하세요"))]
+    pub course_description: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_format: Option<String>,
+    #[validate(length(max = 200, message = "200 글자 이하로 입력하세요"))]
+    pub course_structure: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_duration: Option<String>,
+    #[validate(range(min = 0, message = "0 이상의 값을 입력하세요"))]
+    pub course_price: Option<i32>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_language: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_level: Option<String>,
+    /// 수강할 수 있는 최대 학생 수. 없으면 제한 없음
+    #[validate(range(min = 1, message = "1 이상의 값을 입력하세요"))]
+    pub course_capacity: Option<i32>,
+}
+
+impl TryFrom<web::Json<CreateCourse>> for CreateCourse {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<CreateCourse>) -> Result<Self, Self::Error> {
+        Ok(CreateCourse {
+            tutor_id: value.tutor_id,
+            course_name: value.course_name.clone(),
+            course_description: value.course_description.clone(),
+            course_format: value.course_format.clone(),
+            course_structure: value.course_structure.clone(),
+            course_duration: value.course_duration.clone(),
+            course_price: value.course_price,
+            course_language: value.course_language.clone(),
+            course_level: value.course_level.clone(),
+            course_capacity: value.course_capacity,
+        })
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Default, Validate)]
+pub struct UpdateCourse {
+    #[validate(length(min = 1, max = 140, message = "1~140 글자로 입력하세요"))]
+    pub course_name: Option<String>,
+    #[validate(length(max = 2000, message = "2000 글자 이하로 입력하세요"))]
+    pub course_description: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_format: Option<String>,
+    #[validate(length(max = 200, message = "200 글자 이하로 입력하세요"))]
+    pub course_structure: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_duration: Option<String>,
+    #[validate(range(min = 0, message = "0 이상의 값을 입력하세요"))]
+    pub course_price: Option<i32>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_language: Option<String>,
+    #[validate(length(max = 30, message = "30 글자 이하로 입력하세요"))]
+    pub course_level: Option<String>,
+    /// 수강할 수 있는 최대 학생 수. 없으면 제한 없음
+    #[validate(range(min = 1, message = "1 이상의 값을 입력하세요"))]
+    pub course_capacity: Option<i32>,
+}
+
+impl TryFrom<web::Json<UpdateCourse>> for UpdateCourse {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<UpdateCourse>) -> Result<Self, Self::Error> {
+        Ok(UpdateCourse {
+            course_name: value.course_name.clone(),
+            course_description: value.course_description.clone(),
+            course_format: value.course_format.clone(),
+            course_structure: value.course_structure.clone(),
+            course_duration: value.course_duration.clone(),
+            course_price: value.course_price,
+            course_language: value.course_language.clone(),
+            course_level: value.course_level.clone(),
+            course_capacity: value.course_capacity,
+        })
+    }
+}
+
+/// 한 페이지에 담을 course 수의 기본값
+pub const DEFAULT_PAGE_SIZE: i64 = 20;
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CourseSort {
+    #[default]
+    CourseId,
+    PostedTime,
+    CourseName,
+}
+
+impl CourseSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CourseSort::CourseId => "course_id",
+            CourseSort::PostedTime => "posted_time",
+            CourseSort::CourseName => "course_name",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// `GET /courses/{tutor_id}` 의 페이지네이션, 정렬, 필터 조건
+#[derive(Deserialize, Serialize, IntoParams, Debug, Clone, Default, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct CourseQuery {
+    /// 한 페이지에 담을 course 수 (기본값 20)
+    #[validate(range(min = 1, max = 100, message = "1~100 사이의 값을 입력하세요"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// 건너뛸 course 수 (기본값 0)
+    #[validate(range(min = 0, message = "0 이상의 값을 입력하세요"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    /// 정렬 기준 (기본값 course_id)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<CourseSort>,
+    /// 정렬 방향 (기본값 asc)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+    /// course_name에 포함된 문자열 (대소문자 구분 없음)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// 삭제된 course도 포함 (기본값 false, 해당 tutor로 인증해야 함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_deleted: Option<bool>,
+}
+
+impl CourseQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0)
+    }
+
+    pub fn sort(&self) -> CourseSort {
+        self.sort.unwrap_or_default()
+    }
+
+    pub fn order(&self) -> SortOrder {
+        self.order.unwrap_or_default()
+    }
+
+    pub fn include_deleted(&self) -> bool {
+        self.include_deleted.unwrap_or(false)
+    }
+}
+
+/// course 목록 응답 envelope
+#[derive(Serialize, ToSchema, Debug)]
+pub struct CoursePage {
+    pub courses: Vec<Course>,
+    /// 조건에 맞는 전체 course 수
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    /// 다음 페이지 경로, 마지막 페이지이면 null
+    pub next: Option<String>,
+}
+
+/// `GET /courses/search` 의 검색어와 페이지네이션 조건
+#[derive(Deserialize, Serialize, IntoParams, Debug, Clone, Default, Validate)]
+#[into_params(parameter_in = Query)]
+pub struct CourseSearchQuery {
+    /// 검색어
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub q: String,
+    /// 한 페이지에 담을 결과 수 (기본값 20)
+    #[validate(range(min = 1, max = 100, message = "1~100 사이의 값을 입력하세요"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+    /// 건너뛸 결과 수 (기본값 0)
+    #[validate(range(min = 0, message = "0 이상의 값을 입력하세요"))]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+}
+
+impl CourseSearchQuery {
+    pub fn limit(&self) -> i64 {
+        self.limit.unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
+    pub fn offset(&self) -> i64 {
+        self.offset.unwrap_or(0)
+    }
+}
+
+#[derive(Serialize, ToSchema, Debug)]
+pub struct CourseSearchHit {
+    #[serde(flatten)]
+    pub course: Course,
+    /// 검색어와의 관련도, 클수록 관련도가 높음
+    pub rank: f32,
+    /// 검색어가 `<b>`, `</b>`로 강조된 course 이름과 설명 일부
+    pub snippet: String,
+}
+
+/// course 검색 응답 envelope
+#[derive(Serialize, ToSchema, Debug)]
+pub struct CourseSearchPage {
+    pub results: Vec<CourseSearchHit>,
+    /// 검색어에 맞는 전체 course 수
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+    /// 다음 페이지 경로, 마지막 페이지이면 null
+    pub next: Option<String>,
+}
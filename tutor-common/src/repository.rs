@@ -0,0 +1,58 @@
+use super::errors::EzyTutorError;
+use super::models::{
+    Course, CourseQuery, CourseSearchHit, CourseSearchQuery, CreateCourse, UpdateCourse,
+    VersionMatch,
+};
+use async_trait::async_trait;
+
+/// Course 저장소 추상화.
+///
+/// 핸들러는 이 trait에만 의존하므로 in-memory 저장소(tutor-nodb)와
+/// PostgreSQL 저장소(tutor-db) 어느 쪽이든 같은 코드로 동작한다.
+#[async_trait]
+pub trait CourseRepository: Send + Sync {
+    /// 조건에 맞는 course 한 페이지와 조건에 맞는 전체 course 수를 반환한다.
+    /// `include_deleted`이면 영구 삭제되기 전의 삭제된 course도 포함한다.
+    async fn get_courses_for_tutor(
+        &self,
+        tutor_id: i32,
+        query: &CourseQuery,
+    ) -> Result<(Vec<Course>, i64), EzyTutorError>;
+
+    /// 모든 tutor의 course에서 검색어와 관련도가 높은 순으로 한 페이지와
+    /// 검색어에 맞는 전체 course 수를 반환한다.
+    async fn search_courses(
+        &self,
+        query: &CourseSearchQuery,
+    ) -> Result<(Vec<CourseSearchHit>, i64), EzyTutorError>;
+
+    async fn get_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+    ) -> Result<Course, EzyTutorError>;
+
+    async fn post_new_course(&self, new_course: CreateCourse) -> Result<Course, EzyTutorError>;
+
+    /// 현재 버전이 `expected`와 맞지 않으면 `PreconditionFailed`를 돌려주고, 수정하면 버전을 올린다.
+    async fn update_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        update_course: UpdateCourse,
+        expected: &VersionMatch,
+    ) -> Result<Course, EzyTutorError>;
+
+    /// 삭제 시각만 기록하고 버전을 올린다. 삭제한 course는 이후 조회, 수정에서 찾을 수 없고
+    /// `include_deleted` 목록과 `restore_course`에서만 보이며, 보관 기간이 지나면 영구 삭제된다.
+    /// 버전 조건은 `update_course_details`와 같다.
+    async fn delete_course(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        expected: &VersionMatch,
+    ) -> Result<String, EzyTutorError>;
+
+    /// 영구 삭제되기 전의 삭제된 course를 되살리고 버전을 올린다.
+    async fn restore_course(&self, tutor_id: i32, course_id: i32) -> Result<Course, EzyTutorError>;
+}
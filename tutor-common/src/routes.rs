@@ -0,0 +1,16 @@
+use super::handlers::*;
+use utoipa_actix_web::service_config::ServiceConfig;
+
+pub fn course_routes(config: &mut ServiceConfig) {
+    config.service(
+        utoipa_actix_web::scope("/courses")
+            // "/search"가 "/{tutor_id}"보다 먼저 매칭되도록 먼저 등록
+            .service(search_courses)
+            .service(post_new_course)
+            .service(get_courses_for_tutor)
+            .service(get_course_details)
+            .service(update_course_details)
+            .service(delete_course)
+            .service(restore_course),
+    );
+}
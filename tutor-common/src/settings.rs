@@ -0,0 +1,383 @@
+//! 서비스 설정: 코드 기본값 < TOML 파일 < 환경 변수 순으로 덮어쓴다.
+//!
+//! 환경 변수는 `EZY__<섹션>__<키>` 형식을 사용한다. (예: `EZY__SERVER__WORKERS=4`)
+//! 기존 `.env`와의 호환을 위해 `DATABASE_URL` 같은 이전 변수 이름도 계속 읽으며,
+//! 둘 다 지정되면 `EZY__` 변수가 우선한다.
+
+use super::errors::EzyTutorError;
+use super::telemetry::REQUEST_ID_HEADER;
+use actix_cors::Cors;
+use config::{Config, Environment, File, FileFormat, FileSourceFile};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+use validator::{Validate, ValidationError};
+
+/// 설정 파일 경로를 지정하는 환경 변수. 지정하면 파일이 반드시 있어야 한다.
+pub const CONFIG_FILE_ENV: &str = "APP_CONFIG";
+
+/// `APP_CONFIG`이 없을 때 읽는 설정 파일. 작업 디렉터리 기준이며, 없으면 기본값과 환경 변수만 쓴다.
+pub const DEFAULT_CONFIG_FILE: &str = "config.toml";
+
+/// 이전부터 쓰던 환경 변수 이름과 설정 키
+const LEGACY_ENV: &[(&str, &str)] = &[
+    ("HOST_PORT", "server.bind_address"),
+    ("DATABASE_URL", "database.url"),
+    ("JWT_SECRET", "auth.jwt_secret"),
+    ("RUN_MIGRATIONS", "features.run_migrations"),
+    ("COURSE_DATA_DIR", "storage.data_dir"),
+    ("COURSE_COMPACT_EVERY", "storage.compact_every"),
+];
+
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(default)]
+pub struct Settings {
+    #[validate(nested)]
+    pub server: ServerSettings,
+    #[validate(nested)]
+    pub database: DatabaseSettings,
+    #[validate(nested)]
+    pub log: LogSettings,
+    #[validate(nested)]
+    pub cors: CorsSettings,
+    pub features: FeatureSettings,
+    #[validate(nested)]
+    pub auth: AuthSettings,
+    #[validate(nested)]
+    pub storage: StorageSettings,
+    #[validate(nested)]
+    pub retention: RetentionSettings,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(default)]
+pub struct ServerSettings {
+    #[validate(custom(function = "validate_bind_address"))]
+    pub bind_address: String,
+    /// 지정하지 않으면 CPU 코어 수만큼 실행
+    #[validate(range(min = 1, max = 256, message = "1~256 사이로 입력하세요"))]
+    pub workers: Option<usize>,
+    pub health_check_response: String,
+    /// 종료 신호 후 readiness를 실패로 알린 채 요청을 계속 받는 시간 (로드 밸런서가 빼낼 시간)
+    pub shutdown_delay_secs: u64,
+    /// 새 연결을 막은 뒤 처리 중인 요청이 끝나기를 기다리는 최대 시간
+    pub shutdown_timeout_secs: u64,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        ServerSettings {
+            bind_address: "127.0.0.1:3000".into(),
+            workers: None,
+            health_check_response: "I'm good. You've already asked me ".into(),
+            shutdown_delay_secs: 0,
+            shutdown_timeout_secs: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(default)]
+pub struct DatabaseSettings {
+    pub url: Option<String>,
+    #[validate(range(min = 1, max = 100, message = "1~100 사이로 입력하세요"))]
+    pub max_connections: u32,
+    #[validate(range(
+        max = self.max_connections,
+        message = "max_connections 이하로 입력하세요"
+    ))]
+    pub min_connections: u32,
+    #[validate(range(min = 1, message = "1초 이상으로 입력하세요"))]
+    pub acquire_timeout_secs: u64,
+    pub idle_timeout_secs: u64,
+    /// 쿼리 하나의 최대 실행 시간. 0이면 제한 없음
+    pub statement_timeout_secs: u64,
+    /// 시작할 때 DB 연결 실패 시 다시 시도하는 횟수
+    pub connect_retries: u32,
+    #[validate(range(min = 1, message = "1ms 이상으로 입력하세요"))]
+    pub connect_initial_backoff_ms: u64,
+    #[validate(range(min = 1, message = "1ms 이상으로 입력하세요"))]
+    pub connect_max_backoff_ms: u64,
+}
+
+impl Default for DatabaseSettings {
+    fn default() -> Self {
+        DatabaseSettings {
+            url: None,
+            max_connections: 10,
+            min_connections: 0,
+            acquire_timeout_secs: 5,
+            idle_timeout_secs: 600,
+            statement_timeout_secs: 30,
+            connect_retries: 5,
+            connect_initial_backoff_ms: 500,
+            connect_max_backoff_ms: 10_000,
+        }
+    }
+}
+
+impl DatabaseSettings {
+    pub fn url(&self) -> Result<&str, String> {
+        self.url
+            .as_deref()
+            .filter(|url| !url.is_empty())
+            .ok_or_else(|| "database.url (or DATABASE_URL) is not set".to_string())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(default)]
+pub struct LogSettings {
+    #[validate(custom(function = "validate_log_level"))]
+    pub level: String,
+}
+
+impl Default for LogSettings {
+    fn default() -> Self {
+        LogSettings {
+            level: "info".into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(default)]
+pub struct CorsSettings {
+    /// 비어 있으면 다른 origin의 요청을 허용하지 않음
+    #[validate(custom(function = "validate_origins"))]
+    pub allowed_origins: Vec<String>,
+}
+
+impl CorsSettings {
+    pub fn cors(&self) -> Cors {
+        self.allowed_origins
+            .iter()
+            .fold(Cors::default(), |cors, origin| cors.allowed_origin(origin))
+            .allow_any_method()
+            .allow_any_header()
+            .expose_headers(["Location", "ETag", REQUEST_ID_HEADER])
+            .max_age(3600)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct FeatureSettings {
+    /// 시작할 때 DB 마이그레이션 적용 (tutor-db)
+    pub run_migrations: bool,
+    /// `/swagger-ui/`와 `/api-docs/openapi.json` 제공
+    pub swagger_ui: bool,
+    /// 보관 기간이 지난 삭제된 course를 주기적으로 영구 삭제 (tutor-db)
+    pub purge_deleted_courses: bool,
+}
+
+impl Default for FeatureSettings {
+    fn default() -> Self {
+        FeatureSettings {
+            run_migrations: false,
+            swagger_ui: true,
+            purge_deleted_courses: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Validate)]
+#[serde(default)]
+pub struct AuthSettings {
+    #[validate(length(
+        min = 16,
+        message = "16 글자 이상이어야 합니다 (JWT_SECRET 또는 EZY__AUTH__JWT_SECRET)"
+    ))]
+    pub jwt_secret: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(default)]
+pub struct StorageSettings {
+    /// 지정하면 tutor-nodb가 course를 이 디렉터리의 파일에 유지
+    pub data_dir: Option<String>,
+    #[validate(range(min = 1, message = "1 이상으로 입력하세요"))]
+    pub compact_every: usize,
+}
+
+impl Default for StorageSettings {
+    fn default() -> Self {
+        StorageSettings {
+            data_dir: None,
+            compact_every: 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(default)]
+pub struct RetentionSettings {
+    /// 삭제된 course를 영구 삭제하기 전까지 보관하는 기간
+    #[validate(range(min = 1, max = 3650, message = "1~3650 사이로 입력하세요"))]
+    pub deleted_course_days: i32,
+    /// 영구 삭제할 course를 찾는 주기
+    #[validate(range(min = 1, message = "1초 이상으로 입력하세요"))]
+    pub purge_interval_secs: u64,
+}
+
+impl Default for RetentionSettings {
+    fn default() -> Self {
+        RetentionSettings {
+            deleted_course_days: 30,
+            purge_interval_secs: 60 * 60,
+        }
+    }
+}
+
+impl Settings {
+    /// `APP_CONFIG` 또는 `DEFAULT_CONFIG_FILE`의 TOML 파일과 환경 변수로 설정을 읽고 검증한다.
+    pub fn load() -> Result<Settings, String> {
+        let file = config_file(env::var(CONFIG_FILE_ENV).ok())?;
+
+        // 이전 변수 이름은 EZY__ 형식으로 바꿔 EZY__ 환경 변수보다 먼저 적용
+        let legacy_env = LEGACY_ENV
+            .iter()
+            .filter_map(|(name, key)| {
+                env::var(name).ok().map(|value| {
+                    (
+                        format!("EZY__{}", key.replace('.', "__").to_uppercase()),
+                        value,
+                    )
+                })
+            })
+            .collect::<HashMap<_, _>>();
+
+        let builder = Config::builder()
+            .add_source(file)
+            .add_source(environment().source(Some(legacy_env)))
+            .add_source(environment());
+        let settings: Settings = builder
+            .build()
+            .and_then(|config| config.try_deserialize())
+            .map_err(|error| format!("Failed to load configuration: {}", error))?;
+        settings
+            .validate()
+            .map_err(|errors| format!("Invalid configuration: {}", EzyTutorError::from(errors)))?;
+
+        Ok(settings)
+    }
+}
+
+/// 명시적으로 지정한 설정 파일이 없으면 기본값으로 넘어가지 않고 에러를 돌려준다.
+fn config_file(explicit_path: Option<String>) -> Result<File<FileSourceFile, FileFormat>, String> {
+    match explicit_path {
+        Some(path) if !Path::new(&path).is_file() => Err(format!(
+            "Configuration file {} (set by {}) does not exist",
+            path, CONFIG_FILE_ENV
+        )),
+        Some(path) => Ok(File::new(&path, FileFormat::Toml).required(true)),
+        None => Ok(File::new(DEFAULT_CONFIG_FILE, FileFormat::Toml).required(false)),
+    }
+}
+
+fn environment() -> Environment {
+    Environment::with_prefix("EZY")
+        .prefix_separator("__")
+        .separator("__")
+        .list_separator(",")
+        .with_list_parse_key("cors.allowed_origins")
+        .try_parsing(true)
+}
+
+fn validate_bind_address(bind_address: &str) -> Result<(), ValidationError> {
+    match bind_address.rsplit_once(':') {
+        Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => Ok(()),
+        _ => Err(ValidationError::new("bind_address")
+            .with_message("host:port 형식으로 입력하세요".into())),
+    }
+}
+
+fn validate_log_level(level: &str) -> Result<(), ValidationError> {
+    match level {
+        "trace" | "debug" | "info" | "warn" | "error" | "off" => Ok(()),
+        _ => Err(ValidationError::new("log_level")
+            .with_message("trace, debug, info, warn, error, off 중 하나를 입력하세요".into())),
+    }
+}
+
+fn validate_origins(origins: &[String]) -> Result<(), ValidationError> {
+    if origins
+        .iter()
+        .all(|origin| origin.starts_with("http://") || origin.starts_with("https://"))
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::new("allowed_origins")
+            .with_message("http:// 또는 https://로 시작하는 origin을 입력하세요".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_settings_report_every_field() {
+        let settings = Settings {
+            server: ServerSettings {
+                bind_address: "3000".into(),
+                ..ServerSettings::default()
+            },
+            log: LogSettings {
+                level: "loud".into(),
+            },
+            ..Settings::default()
+        };
+
+        let message = EzyTutorError::from(settings.validate().unwrap_err()).to_string();
+        for field in ["server.bind_address", "log.level", "auth.jwt_secret"] {
+            assert!(
+                message.contains(field),
+                "{} is missing in {}",
+                field,
+                message
+            );
+        }
+    }
+
+    #[test]
+    fn default_settings_with_secret_are_valid() {
+        let settings = Settings {
+            auth: AuthSettings {
+                jwt_secret: "0123456789abcdef".into(),
+            },
+            ..Settings::default()
+        };
+
+        assert!(settings.validate().is_ok());
+        assert!(settings.database.url().is_err());
+    }
+
+    #[test]
+    fn min_connections_cannot_exceed_max_connections() {
+        let settings = Settings {
+            database: DatabaseSettings {
+                max_connections: 5,
+                min_connections: 6,
+                ..DatabaseSettings::default()
+            },
+            auth: AuthSettings {
+                jwt_secret: "0123456789abcdef".into(),
+            },
+            ..Settings::default()
+        };
+
+        let message = EzyTutorError::from(settings.validate().unwrap_err()).to_string();
+        assert!(message.contains("database.min_connections"), "{}", message);
+    }
+
+    #[test]
+    fn missing_explicit_config_file_is_an_error() {
+        let message = config_file(Some("no/such/config.toml".into())).unwrap_err();
+        assert!(message.contains("no/such/config.toml"));
+        assert!(message.contains(CONFIG_FILE_ENV));
+
+        assert!(config_file(None).is_ok());
+    }
+}
@@ -0,0 +1,60 @@
+//! 종료 신호(SIGINT, SIGTERM) 처리.
+//!
+//! 신호를 받으면 readiness를 실패로 바꾸고 `shutdown_delay_secs`만큼 기다린 뒤
+//! 새 연결을 막고, 처리 중인 요청은 `shutdown_timeout_secs`까지 끝나기를 기다린다.
+//! 서버가 멈춘 뒤의 정리(상태 저장, 커넥션 풀 닫기)는 각 서비스가 `serve` 이후에 한다.
+
+use super::health::Readiness;
+use super::settings::ServerSettings;
+use actix_web::{
+    dev::Server,
+    rt::{signal, spawn, time::sleep},
+    web,
+};
+use futures_util::future::{select, Either};
+use std::io;
+use std::pin::pin;
+use std::time::Duration;
+
+/// `HttpServer::disable_signals()`로 만든 서버를 종료 신호가 올 때까지 실행한다.
+pub async fn serve(
+    server: Server,
+    readiness: web::Data<Readiness>,
+    settings: &ServerSettings,
+) -> io::Result<()> {
+    let handle = server.handle();
+    let delay = Duration::from_secs(settings.shutdown_delay_secs);
+
+    spawn(async move {
+        let signal = wait_for_signal().await;
+        tracing::info!(signal, "Shutdown signal received");
+        readiness.begin_shutdown();
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+        tracing::info!("Stopping server and draining in-flight requests");
+        handle.stop(true).await;
+    });
+
+    let result = server.await;
+    tracing::info!("Server stopped");
+    result
+}
+
+#[cfg(unix)]
+async fn wait_for_signal() -> &'static str {
+    use signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate()).expect("failed to listen for SIGTERM");
+    let received = match select(pin!(signal::ctrl_c()), pin!(terminate.recv())).await {
+        Either::Left(_) => "SIGINT",
+        Either::Right(_) => "SIGTERM",
+    };
+    received
+}
+
+#[cfg(not(unix))]
+async fn wait_for_signal() -> &'static str {
+    let _ = signal::ctrl_c().await;
+    "SIGINT"
+}
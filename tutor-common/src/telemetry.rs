@@ -0,0 +1,164 @@
+//! 구조화된 로그(JSON)와 요청 ID.
+//!
+//! 요청마다 `X-Request-Id`를 받거나 새로 만들어 응답 헤더와 에러 응답에 넣고,
+//! 요청 처리 중에 남는 모든 로그(핸들러, SQL 포함)는 이 ID를 가진 `request` span 아래에 기록된다.
+
+use actix_web::{
+    body::MessageBody,
+    dev::{Payload, ServiceRequest, ServiceResponse},
+    http::header::{HeaderName, HeaderValue},
+    middleware::Next,
+    FromRequest, HttpMessage, HttpRequest,
+};
+use std::convert::Infallible;
+use std::fmt;
+use std::future::{ready, Ready};
+use std::time::Instant;
+use tracing::Instrument;
+use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// 클라이언트가 보낸 요청 ID는 이 길이까지만 그대로 사용
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// JSON 형식으로 stdout에 로그를 남긴다. `log` crate로 남긴 로그(sqlx 등)도 함께 기록된다.
+pub fn init(level: &str) {
+    tracing_subscriber::fmt()
+        .json()
+        .with_env_filter(EnvFilter::new(level))
+        .with_current_span(true)
+        .with_span_list(true)
+        .init();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestId(String);
+
+impl RequestId {
+    fn generate() -> Self {
+        RequestId(Uuid::new_v4().to_string())
+    }
+
+    /// 로그와 헤더에 그대로 넣어도 안전한 값만 받아들인다.
+    fn parse(value: &str) -> Option<Self> {
+        let valid = !value.is_empty()
+            && value.len() <= MAX_REQUEST_ID_LEN
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        valid.then(|| RequestId(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for RequestId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// `trace_requests` 미들웨어가 정한 요청 ID. 미들웨어 없이 호출되면 새로 만든다.
+impl FromRequest for RequestId {
+    type Error = Infallible;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Ok(req
+            .extensions()
+            .get::<RequestId>()
+            .cloned()
+            .unwrap_or_else(RequestId::generate)))
+    }
+}
+
+/// 요청 ID를 정하고 요청 전체를 `request` span으로 감싸 처리 결과를 기록하는 미들웨어.
+///
+/// 다른 미들웨어(에러 응답 등)에서 요청 ID를 쓸 수 있도록 가장 바깥에 등록한다.
+pub async fn trace_requests(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<impl MessageBody>, actix_web::Error> {
+    let request_id = req
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(RequestId::parse)
+        .unwrap_or_else(RequestId::generate);
+    req.extensions_mut().insert(request_id.clone());
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.path(),
+    );
+    let started = Instant::now();
+    let res = next.call(req).instrument(span.clone()).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    span.in_scope(|| match res {
+        Ok(mut res) => {
+            tracing::info!(
+                status = res.status().as_u16(),
+                latency_ms,
+                "request completed"
+            );
+            if let Ok(value) = HeaderValue::from_str(request_id.as_str()) {
+                res.headers_mut()
+                    .insert(HeaderName::from_static(REQUEST_ID_HEADER), value);
+            }
+            Ok(res)
+        }
+        Err(error) => {
+            tracing::error!(error = %error, latency_ms, "request failed");
+            Err(error)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{middleware::from_fn, test, web, App, HttpResponse};
+
+    #[actix_rt::test]
+    async fn request_id_is_propagated_or_generated() {
+        let app = test::init_service(App::new().wrap(from_fn(trace_requests)).route(
+            "/",
+            web::get().to(|request_id: RequestId| async move {
+                HttpResponse::Ok().body(request_id.to_string())
+            }),
+        ))
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "client-id-1"))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(
+            resp.headers().get(REQUEST_ID_HEADER).unwrap(),
+            "client-id-1"
+        );
+        assert_eq!(test::read_body(resp).await, "client-id-1");
+
+        // 헤더에 허용하지 않는 문자가 있으면 새 ID를 만든다
+        let req = test::TestRequest::get()
+            .uri("/")
+            .insert_header((REQUEST_ID_HEADER, "bad id\""))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        let generated = resp
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap();
+        assert!(Uuid::parse_str(generated).is_ok());
+    }
+}
@@ -0,0 +1,515 @@
+//! 저장소 구현체와 무관하게 course API 동작을 검증하는 공용 HTTP 테스트 묶음.
+//!
+//! 각 서비스는 자신의 `CourseRepository` 구현체를 넘겨 같은 시나리오를 실행한다.
+
+use super::auth::JwtKeys;
+use super::errors::{problem_details, validation_error_handler, ProblemDetails, PROBLEM_JSON};
+use super::repository::CourseRepository;
+use super::routes::course_routes;
+use super::telemetry::{trace_requests, REQUEST_ID_HEADER};
+use actix_web::{
+    body::MessageBody,
+    dev::ServiceResponse,
+    http::{header, StatusCode},
+    middleware::from_fn,
+    test, web, App,
+};
+use actix_web_validation::validator::ValidatorErrorHandlerExt;
+use serde::Deserialize;
+use std::sync::Arc;
+use utoipa_actix_web::AppExt;
+
+#[derive(Deserialize)]
+struct CourseBody {
+    course_id: i32,
+    tutor_id: i32,
+    course_name: String,
+    deleted_at: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct CourseSearchHitBody {
+    course_id: i32,
+    snippet: String,
+}
+
+#[derive(Deserialize)]
+struct CourseSearchPageBody {
+    results: Vec<CourseSearchHitBody>,
+    total: i64,
+}
+
+#[derive(Deserialize)]
+struct CoursePageBody {
+    courses: Vec<CourseBody>,
+    total: i64,
+    next: Option<String>,
+}
+
+/// 에러 응답이 Problem Details 형식인지 확인하고 본문을 돌려준다.
+async fn read_problem<B: MessageBody>(
+    resp: ServiceResponse<B>,
+    status: StatusCode,
+    instance: &str,
+) -> ProblemDetails {
+    assert_eq!(resp.status(), status);
+    assert_eq!(
+        resp.headers().get(header::CONTENT_TYPE).unwrap(),
+        PROBLEM_JSON
+    );
+    let request_id = resp
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .map(|value| value.to_str().unwrap().to_string());
+    let problem: ProblemDetails = test::read_body_json(resp).await;
+    assert_eq!(problem.status, status.as_u16());
+    assert_eq!(problem.instance.as_deref(), Some(instance));
+    // 에러 응답 본문과 헤더의 요청 ID가 같아야 로그와 대조할 수 있음
+    assert!(problem.request_id.is_some());
+    assert_eq!(problem.request_id, request_id);
+    problem
+}
+
+fn etag_of<B>(resp: &ServiceResponse<B>) -> String {
+    resp.headers()
+        .get(header::ETAG)
+        .expect("ETag header is missing")
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// 주어진 저장소로 course 생성/조회/수정/삭제 시나리오를 실행한다.
+///
+/// `tutor_id`는 저장소에 course를 등록할 수 있는 tutor여야 한다.
+pub async fn course_api_suite(repository: Arc<dyn CourseRepository>, tutor_id: i32) {
+    let keys = JwtKeys::new(b"course api suite secret");
+    let auth = (
+        header::AUTHORIZATION,
+        format!("Bearer {}", keys.issue(tutor_id).unwrap()),
+    );
+    let other_auth = (
+        header::AUTHORIZATION,
+        format!("Bearer {}", keys.issue(tutor_id + 1000).unwrap()),
+    );
+    let app = test::init_service(
+        App::new()
+            .into_utoipa_app()
+            .map(|app| {
+                app.wrap(from_fn(problem_details))
+                    .wrap(from_fn(trace_requests))
+            })
+            .app_data(web::Data::new(keys))
+            .app_data(web::Data::from(repository))
+            .configure(course_routes)
+            .into_app()
+            .validator_error_handler(Arc::new(validation_error_handler)),
+    )
+    .await;
+
+    // 인증 없이 또는 다른 tutor로 생성
+    let payload = format!(
+        r#"{{"tutor_id": {}, "course_name": "Suite course"}}"#,
+        tutor_id
+    );
+    let req = test::TestRequest::post()
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(payload.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert!(resp.headers().contains_key(header::WWW_AUTHENTICATE));
+    read_problem(resp, StatusCode::UNAUTHORIZED, "/courses/").await;
+
+    let req = test::TestRequest::post()
+        .insert_header((header::AUTHORIZATION, "Bearer not-a-token"))
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(payload.clone())
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let req = test::TestRequest::post()
+        .insert_header(other_auth.clone())
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(payload)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    read_problem(resp, StatusCode::FORBIDDEN, "/courses/").await;
+
+    // 생성
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(format!(
+            r#"{{"tutor_id": {}, "course_name": "Suite course", "course_price": 100}}"#,
+            tutor_id
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+
+    let location = resp
+        .headers()
+        .get(header::LOCATION)
+        .expect("Location header is missing")
+        .to_str()
+        .unwrap()
+        .to_string();
+    let created: CourseBody = test::read_body_json(resp).await;
+    assert_eq!(created.tutor_id, tutor_id);
+    assert_eq!(
+        location,
+        format!("/courses/{}/{}", tutor_id, created.course_id)
+    );
+
+    // 단건 조회 (ETag가 같으면 304)
+    let req = test::TestRequest::get().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let mut etag = etag_of(&resp);
+    let fetched: CourseBody = test::read_body_json(resp).await;
+    assert_eq!(fetched.course_name, "Suite course");
+
+    let req = test::TestRequest::get()
+        .uri(&location)
+        .insert_header((header::IF_NONE_MATCH, etag.clone()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(etag_of(&resp), etag);
+
+    // 목록 조회
+    let req = test::TestRequest::get()
+        .uri(&format!("/courses/{}", tutor_id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CoursePageBody = test::read_body_json(resp).await;
+    assert!(page
+        .courses
+        .iter()
+        .any(|course| course.course_id == created.course_id));
+
+    // 페이지네이션, 정렬, 이름 필터
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(format!(
+            r#"{{"tutor_id": {}, "course_name": "Suite course 2"}}"#,
+            tutor_id
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::CREATED);
+    let second: CourseBody = test::read_body_json(resp).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/courses/{}?name=SUITE%20COURSE&limit=1&sort=course_name&order=desc",
+            tutor_id
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CoursePageBody = test::read_body_json(resp).await;
+    assert_eq!(page.courses.len(), 1);
+    assert_eq!(page.courses[0].course_name, "Suite course 2");
+    assert!(page.total >= 2);
+    let next = page.next.expect("next page link is missing");
+
+    let req = test::TestRequest::get().uri(&next).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CoursePageBody = test::read_body_json(resp).await;
+    assert_eq!(page.courses.len(), 1);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/courses/{}?limit=0", tutor_id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // 조건에 맞는 course가 없으면 빈 페이지
+    for uri in [
+        format!("/courses/{}?name=nosuchcoursename", tutor_id),
+        format!("/courses/{}?offset=100000", tutor_id),
+        format!("/courses/{}", tutor_id + 1000),
+    ] {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK, "{}", uri);
+        let page: CoursePageBody = test::read_body_json(resp).await;
+        assert!(page.courses.is_empty());
+        assert!(page.next.is_none());
+        if !uri.contains("offset") {
+            assert_eq!(page.total, 0);
+        }
+    }
+
+    let req = test::TestRequest::delete()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, "*"))
+        .uri(&format!("/courses/{}/{}", tutor_id, second.course_id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/courses/{}/{}", tutor_id, second.course_id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    // 삭제된 course를 포함한 목록은 해당 tutor만 조회
+    let include_deleted = format!("/courses/{}?include_deleted=true", tutor_id);
+    let req = test::TestRequest::get().uri(&include_deleted).to_request();
+    let resp = test::call_service(&app, req).await;
+    read_problem(
+        resp,
+        StatusCode::UNAUTHORIZED,
+        &format!("/courses/{}", tutor_id),
+    )
+    .await;
+
+    let req = test::TestRequest::get()
+        .insert_header(other_auth.clone())
+        .uri(&include_deleted)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::get()
+        .insert_header(auth.clone())
+        .uri(&format!(
+            "{}&name=Suite%20course%202&limit=100",
+            include_deleted
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CoursePageBody = test::read_body_json(resp).await;
+    let deleted = page
+        .courses
+        .iter()
+        .find(|course| course.course_id == second.course_id)
+        .expect("deleted course is missing");
+    assert!(deleted.deleted_at.is_some());
+
+    // 삭제된 course는 해당 tutor만 복구할 수 있고, 복구하면 다시 조회됨
+    let restore = format!("/courses/{}/{}/restore", tutor_id, second.course_id);
+    let req = test::TestRequest::post().uri(&restore).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    let req = test::TestRequest::post()
+        .insert_header(other_auth.clone())
+        .uri(&restore)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri(&restore)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    etag_of(&resp);
+    let restored: CourseBody = test::read_body_json(resp).await;
+    assert_eq!(restored.course_id, second.course_id);
+    assert!(restored.deleted_at.is_none());
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/courses/{}/{}", tutor_id, second.course_id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri(&restore)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    read_problem(resp, StatusCode::NOT_FOUND, &restore).await;
+
+    // 다른 tutor의 course는 수정/삭제할 수 없음
+    let req = test::TestRequest::patch()
+        .insert_header(other_auth.clone())
+        .uri(&location)
+        .insert_header(header::ContentType::json())
+        .set_payload(r#"{"course_name": "Hijacked"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::delete()
+        .insert_header(other_auth)
+        .uri(&location)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let req = test::TestRequest::delete().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+    // If-Match 없이, 또는 이전 버전으로는 수정할 수 없음
+    let req = test::TestRequest::patch()
+        .insert_header(auth.clone())
+        .uri(&location)
+        .insert_header(header::ContentType::json())
+        .set_payload(r#"{"course_name": "Without version"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    read_problem(resp, StatusCode::PRECONDITION_REQUIRED, &location).await;
+
+    let req = test::TestRequest::delete()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, r#""999""#))
+        .uri(&location)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let problem = read_problem(resp, StatusCode::PRECONDITION_FAILED, &location).await;
+    assert_eq!(problem.problem_type, "/problems/precondition-failed");
+
+    // 수정 (PUT, PATCH 모두 지원). 수정할 때마다 ETag가 바뀜
+    let first_etag = etag.clone();
+    for (req, course_name) in [
+        (test::TestRequest::put(), "Suite course (put)"),
+        (test::TestRequest::patch(), "Suite course (patch)"),
+    ] {
+        let req = req
+            .insert_header(auth.clone())
+            .insert_header((header::IF_MATCH, etag.clone()))
+            .uri(&location)
+            .insert_header(header::ContentType::json())
+            .set_payload(format!(r#"{{"course_name": "{}"}}"#, course_name))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_ne!(etag_of(&resp), etag);
+        etag = etag_of(&resp);
+        let updated: CourseBody = test::read_body_json(resp).await;
+        assert_eq!(updated.course_name, course_name);
+    }
+
+    let req = test::TestRequest::patch()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, first_etag.clone()))
+        .uri(&location)
+        .insert_header(header::ContentType::json())
+        .set_payload(r#"{"course_name": "Stale edit"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::PRECONDITION_FAILED);
+
+    let req = test::TestRequest::get()
+        .uri(&location)
+        .insert_header((header::IF_NONE_MATCH, first_etag))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // 검색
+    let req = test::TestRequest::patch()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, etag.clone()))
+        .uri(&location)
+        .insert_header(header::ContentType::json())
+        .set_payload(r#"{"course_description": "Learn the Qwertyzx framework step by step"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let etag = etag_of(&resp);
+
+    let req = test::TestRequest::get()
+        .uri("/courses/search?q=qwertyzx")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CourseSearchPageBody = test::read_body_json(resp).await;
+    assert_eq!(page.total, 1);
+    assert_eq!(page.results[0].course_id, created.course_id);
+    assert!(page.results[0].snippet.contains("<b>Qwertyzx</b>"));
+
+    let req = test::TestRequest::get()
+        .uri("/courses/search?q=nosuchcoursekeyword")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+    let page: CourseSearchPageBody = test::read_body_json(resp).await;
+    assert_eq!(page.total, 0);
+    assert!(page.results.is_empty());
+
+    let req = test::TestRequest::get()
+        .uri("/courses/search?q=")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+    // 유효성 검사 실패
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload(format!(
+            r#"{{"tutor_id": {}, "course_name": ""}}"#,
+            tutor_id
+        ))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let problem = read_problem(resp, StatusCode::BAD_REQUEST, "/courses/").await;
+    assert!(problem
+        .errors
+        .iter()
+        .any(|error| error.field == "course_name"));
+
+    // 잘못된 JSON 본문도 Problem Details로 응답
+    let req = test::TestRequest::post()
+        .insert_header(auth.clone())
+        .uri("/courses/")
+        .insert_header(header::ContentType::json())
+        .set_payload("{not json")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let problem = read_problem(resp, StatusCode::BAD_REQUEST, "/courses/").await;
+    assert_eq!(problem.problem_type, "about:blank");
+
+    // 삭제
+    let req = test::TestRequest::delete()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, etag))
+        .uri(&location)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    // 삭제된 course는 찾을 수 없음
+    let req = test::TestRequest::get().uri(&location).to_request();
+    let resp = test::call_service(&app, req).await;
+    let problem = read_problem(resp, StatusCode::NOT_FOUND, &location).await;
+    assert_eq!(problem.problem_type, "/problems/not-found");
+
+    let req = test::TestRequest::patch()
+        .insert_header(auth.clone())
+        .insert_header((header::IF_MATCH, "*"))
+        .uri(&location)
+        .insert_header(header::ContentType::json())
+        .set_payload(r#"{"course_name": "Gone"}"#)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+    let req = test::TestRequest::delete()
+        .insert_header(auth)
+        .insert_header((header::IF_MATCH, "*"))
+        .uri(&location)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+}
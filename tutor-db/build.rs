@@ -0,0 +1,4 @@
+// sqlx::migrate!로 포함한 마이그레이션 파일이 바뀌면 다시 빌드
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
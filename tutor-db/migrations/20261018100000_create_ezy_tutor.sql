@@ -0,0 +1,24 @@
+-- Add migration script here
+drop table if exists ezy_tutor;
+
+-- 테이블 생성
+create table ezy_tutor
+(
+    tutor_id serial primary key,
+    tutor_name varchar(200) not null,
+    tutor_pic_url varchar(200) not null,
+    tutor_profile varchar(2000) not null
+);
+
+insert into ezy_tutor (tutor_id, tutor_name, tutor_pic_url, tutor_profile)
+values (1, 'Merlene', 'http://s3.amazon.aws.com/pic1', 'Merlene is an experienced finance professional');
+insert into ezy_tutor (tutor_id, tutor_name, tutor_pic_url, tutor_profile)
+values (2, 'Frank', 'http://s3.amazon.aws.com/pic2', 'Frank is an expert nuclear engineer');
+
+-- 명시적으로 id를 지정해 삽입했으므로 serial 시퀀스를 맞춰줌
+select setval('ezy_tutor_tutor_id_seq', (select max(tutor_id) from ezy_tutor));
+
+-- 존재하지 않는 tutor의 course가 등록되지 않도록 외래 키 추가
+alter table ezy_course_c5
+    add constraint ezy_course_c5_tutor_id_fkey
+    foreign key (tutor_id) references ezy_tutor (tutor_id);
@@ -0,0 +1,13 @@
+-- Add migration script here
+-- course 상세 정보 컬럼 추가
+alter table ezy_course_c5
+    add column course_description varchar(2000),
+    add column course_format varchar(30),
+    add column course_structure varchar(200),
+    add column course_duration varchar(30),
+    add column course_price INT,
+    add column course_language varchar(30),
+    add column course_level varchar(30);
+
+-- 명시적으로 id를 지정해 삽입했으므로 serial 시퀀스를 맞춰줌
+select setval('ezy_course_c5_course_id_seq', (select max(course_id) from ezy_course_c5));
@@ -0,0 +1,5 @@
+-- Add migration script here
+-- course 이름과 설명에 대한 전문 검색(full-text search) 인덱스
+-- 검색 쿼리는 인덱스를 사용하도록 같은 식을 사용해야 함
+create index ezy_course_c5_search_idx on ezy_course_c5
+    using gin (to_tsvector('simple', course_name || ' ' || coalesce(course_description, '')));
@@ -0,0 +1,10 @@
+-- Add migration script here
+drop table if exists ezy_tutor_auth;
+
+-- tutor 로그인 정보 (비밀번호는 argon2 해시로만 저장)
+create table ezy_tutor_auth
+(
+    username varchar(50) primary key,
+    tutor_id INT not null unique references ezy_tutor (tutor_id) on delete cascade,
+    password_hash varchar(200) not null
+);
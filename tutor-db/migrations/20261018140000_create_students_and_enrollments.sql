@@ -0,0 +1,28 @@
+-- Add migration script here
+-- course별 최대 수강 인원 (null이면 제한 없음)
+alter table ezy_course_c5
+    add column course_capacity INT check (course_capacity > 0);
+
+drop table if exists ezy_enrollment;
+drop table if exists ezy_student;
+
+-- 학생 (이메일로 로그인, 비밀번호는 argon2 해시로만 저장)
+create table ezy_student
+(
+    student_id serial primary key,
+    student_name varchar(200) not null,
+    student_email varchar(200) not null unique,
+    password_hash varchar(200) not null
+);
+
+-- 수강 신청 (course나 학생이 삭제되면 함께 삭제)
+create table ezy_enrollment
+(
+    course_id INT not null references ezy_course_c5 (course_id) on delete cascade,
+    student_id INT not null references ezy_student (student_id) on delete cascade,
+    enrolled_time TIMESTAMP not null default now(),
+    primary key (course_id, student_id)
+);
+
+-- 학생별 수강 목록 조회용
+create index ezy_enrollment_student_id_idx on ezy_enrollment (student_id);
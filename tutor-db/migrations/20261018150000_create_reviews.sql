@@ -0,0 +1,45 @@
+-- Add migration script here
+-- course별 리뷰 수와 평점 합계. 목록을 조회할 때마다 리뷰를 집계하지 않도록 trigger로 함께 갱신
+alter table ezy_course_c5
+    add column review_count INT not null default 0,
+    add column rating_total INT not null default 0;
+
+drop table if exists ezy_review;
+
+-- 리뷰 (학생 한 명이 course마다 하나)
+create table ezy_review
+(
+    course_id INT not null references ezy_course_c5 (course_id) on delete cascade,
+    student_id INT not null references ezy_student (student_id) on delete cascade,
+    rating INT not null check (rating between 1 and 5),
+    review_text varchar(2000),
+    posted_time TIMESTAMP not null default now(),
+    updated_time TIMESTAMP,
+    primary key (course_id, student_id)
+);
+
+-- 학생이 삭제되어 리뷰가 cascade로 지워질 때도 집계가 맞도록 trigger에서 처리
+create or replace function ezy_review_update_course_rating() returns trigger as
+$$
+begin
+    if tg_op in ('UPDATE', 'DELETE') then
+        update ezy_course_c5
+        set review_count = review_count - 1,
+            rating_total = rating_total - old.rating
+        where course_id = old.course_id;
+    end if;
+    if tg_op in ('INSERT', 'UPDATE') then
+        update ezy_course_c5
+        set review_count = review_count + 1,
+            rating_total = rating_total + new.rating
+        where course_id = new.course_id;
+    end if;
+    return null;
+end;
+$$ language plpgsql;
+
+create trigger ezy_review_course_rating
+    after insert or update of rating or delete
+    on ezy_review
+    for each row
+execute function ezy_review_update_course_rating();
@@ -0,0 +1,7 @@
+-- Add migration script here
+-- course를 바로 지우지 않고 삭제 시각을 기록 (보관 기간이 지나면 purge 작업이 영구 삭제)
+alter table ezy_course_c5
+    add column deleted_at TIMESTAMP;
+
+-- purge 작업이 보관 기간이 지난 course를 찾을 때 사용
+create index ezy_course_c5_deleted_at_idx on ezy_course_c5 (deleted_at) where deleted_at is not null;
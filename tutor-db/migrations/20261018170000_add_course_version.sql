@@ -0,0 +1,4 @@
+-- Add migration script here
+-- course를 수정할 때마다 1씩 증가하는 버전 (ETag / If-Match로 동시 수정 감지)
+alter table ezy_course_c5
+    add column version INT not null default 1;
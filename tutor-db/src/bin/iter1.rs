@@ -34,7 +34,7 @@ async fn main() -> io::Result<()> {
             course_id: course_row.course_id,
             tutor_id: course_row.tutor_id,
             course_name: course_row.course_name,
-            posted_time: Some(chrono::NaiveDateTime::from(course_row.posted_time.unwrap())),
+            posted_time: course_row.posted_time,
         });
     }
 
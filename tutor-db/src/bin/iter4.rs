@@ -1,45 +1,234 @@
-use actix_web::{web, App, HttpServer};
+use actix_web::{middleware::from_fn, web, App, HttpServer};
+use actix_web_validation::validator::ValidatorErrorHandlerExt;
 use dotenv::dotenv;
-use sqlx::postgres::PgPool;
-use std::sync::Mutex;
-use std::{env, io};
+use std::sync::{Arc, Mutex};
+use std::{io, process};
+use utoipa::OpenApi;
+use utoipa_actix_web::AppExt;
+use utoipa_swagger_ui::SwaggerUi;
 
 #[path = "../iter4/db_access.rs"]
 mod db_access;
-#[path = "../iter4/errors.rs"]
-mod errors;
 #[path = "../iter4/handlers.rs"]
 mod handlers;
 #[path = "../iter4/models.rs"]
 mod models;
+#[path = "../iter4/password.rs"]
+mod password;
+#[path = "../iter4/pool.rs"]
+mod pool;
+#[path = "../iter4/purge.rs"]
+mod purge;
+#[path = "../iter4/repository.rs"]
+mod repository;
 #[path = "../iter4/routes.rs"]
 mod routes;
+#[path = "../iter4/schema.rs"]
+mod schema;
 #[path = "../iter4/state.rs"]
 mod state;
 
+use repository::PgCourseRepository;
 use routes::*;
 use state::AppState;
+use tutor_common::auth::{JwtKeys, SecurityAddon};
+use tutor_common::errors::{problem_details, validation_error_handler};
+use tutor_common::health::Readiness;
+use tutor_common::metrics::{track_requests, Metrics};
+use tutor_common::repository::CourseRepository;
+use tutor_common::settings::Settings;
+use tutor_common::shutdown;
+use tutor_common::telemetry::{self, trace_requests};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::health_check_handler,
+        tutor_common::health::liveness,
+        tutor_common::health::readiness,
+        tutor_common::metrics::metrics_handler,
+        handlers::register_tutor,
+        handlers::login_tutor,
+        handlers::login_student,
+        handlers::get_all_tutors,
+        handlers::get_tutor_details,
+        handlers::update_tutor_details,
+        handlers::delete_tutor,
+        handlers::get_all_students,
+        handlers::get_student_details,
+        handlers::post_new_student,
+        handlers::update_student_details,
+        handlers::delete_student,
+        handlers::get_student_enrollments,
+        handlers::post_new_enrollment,
+        handlers::get_course_enrollments,
+        handlers::delete_enrollment,
+        handlers::post_new_review,
+        handlers::get_course_reviews,
+        handlers::update_review,
+        handlers::delete_review,
+    ),
+    modifiers(&SecurityAddon),
+    tags(
+        (name = "health", description = "service health"),
+        (name = "metrics", description = "Prometheus metrics"),
+        (name = "auth", description = "tutor and student login"),
+        (name = "tutor", description = "tutor management"),
+        (name = "course", description = "course management"),
+        (name = "student", description = "student management"),
+        (name = "enrollment", description = "course enrollment"),
+        (name = "review", description = "course reviews and ratings")
+    )
+)]
+struct ApiDoc;
 
 #[actix_rt::main]
 async fn main() -> io::Result<()> {
     dotenv().ok();
 
-    let database_url = env::var("DATABASE_URL").expect("DATABASE URL is not set in .env file");
-    let db_pool = PgPool::connect(&database_url).await.unwrap();
+    let settings = match Settings::load() {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    };
+    telemetry::init(&settings.log.level);
+
+    let db_pool = match pool::connect_pool(&settings.database).await {
+        Ok(db_pool) => db_pool,
+        Err(message) => {
+            tracing::error!("{}", message);
+            process::exit(1);
+        }
+    };
+
+    if let Err(message) = schema::prepare_schema(&db_pool, settings.features.run_migrations).await {
+        tracing::error!("{}", message);
+        process::exit(1);
+    }
+
+    let purge = settings
+        .features
+        .purge_deleted_courses
+        .then(|| purge::spawn_purge(db_pool.clone(), &settings.retention));
+
+    let jwt_keys = web::Data::new(JwtKeys::new(settings.auth.jwt_secret.as_bytes()));
+
+    let course_repository: Arc<dyn CourseRepository> =
+        Arc::new(PgCourseRepository::new(db_pool.clone()));
+
+    let readiness = web::Data::new(
+        Readiness::new().with("database", Arc::new(pool::PoolHealth(db_pool.clone()))),
+    );
+
+    let metrics = web::Data::new(Metrics::new());
+    pool::register_pool_metrics(&metrics, &db_pool);
 
     let shared_data = web::Data::new(AppState {
-        health_check_response: "I'm good. You've already asked me ".to_string(),
+        health_check_response: settings.server.health_check_response.clone(),
         visit_count: Mutex::new(0),
-        db: db_pool,
+        db: db_pool.clone(),
     });
 
+    let app_settings = settings.clone();
+    let app_readiness = readiness.clone();
     let app = move || {
-        App::new()
+        let app = App::new()
+            .into_utoipa_app()
+            .openapi(ApiDoc::openapi())
+            .map(|app| {
+                app.wrap(from_fn(problem_details))
+                    .wrap(app_settings.cors.cors())
+                    .wrap(from_fn(track_requests))
+                    .wrap(from_fn(trace_requests))
+            })
             .app_data(shared_data.clone())
+            .app_data(app_readiness.clone())
+            .app_data(metrics.clone())
+            .app_data(jwt_keys.clone())
+            .app_data(web::Data::from(course_repository.clone()))
+            .map(|app| app.configure(enrollment_routes).configure(review_routes))
+            .configure(course_routes);
+        let app = if app_settings.features.swagger_ui {
+            app.openapi_service(|api| {
+                SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", api)
+            })
+        } else {
+            app
+        };
+        app.into_app()
+            .validator_error_handler(Arc::new(validation_error_handler))
             .configure(general_routes)
-            .configure(course_routes)
+            .configure(auth_routes)
+            .configure(tutor_routes)
+            .configure(student_routes)
     };
 
-    let host_port = env::var("HOST_PORT").expect("HOST:PORT address is not set in .env file");
-    HttpServer::new(app).bind(&host_port)?.run().await
+    let mut server = HttpServer::new(app)
+        .disable_signals()
+        .shutdown_timeout(settings.server.shutdown_timeout_secs);
+    if let Some(workers) = settings.server.workers {
+        server = server.workers(workers);
+    }
+    let server = server.bind(&settings.server.bind_address)?.run();
+    shutdown::serve(server, readiness, &settings.server).await?;
+
+    // 처리 중이던 요청이 모두 끝난 뒤 커넥션을 정리
+    if let Some(purge) = purge {
+        purge.abort();
+    }
+    db_pool.close().await;
+    tracing::info!("Database pool closed");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, test};
+    use serde_json::Value;
+
+    #[actix_rt::test]
+    async fn openapi_documents_tutor_and_course_routes() {
+        let app = test::init_service(
+            App::new()
+                .into_utoipa_app()
+                .openapi(ApiDoc::openapi())
+                .configure(course_routes)
+                .openapi_service(|api| {
+                    SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", api)
+                })
+                .into_app(),
+        )
+        .await;
+
+        let req = test::TestRequest::get()
+            .uri("/api-docs/openapi.json")
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let api: Value = test::read_body_json(resp).await;
+        for (path, method) in [
+            ("/auth/login", "post"),
+            ("/tutors/{tutor_id}", "patch"),
+            ("/courses/", "post"),
+            ("/courses/{tutor_id}/{course_id}", "delete"),
+        ] {
+            assert!(
+                api["paths"][path][method].is_object(),
+                "{} {} is not documented",
+                method,
+                path
+            );
+        }
+        assert_eq!(
+            api["paths"]["/courses/{tutor_id}/{course_id}"]["get"]["responses"]["404"]["content"]
+                ["application/problem+json"]["schema"]["$ref"],
+            "#/components/schemas/ProblemDetails"
+        );
+        assert!(api["components"]["schemas"]["Tutor"].is_object());
+        assert!(api["components"]["securitySchemes"]["bearer_auth"].is_object());
+    }
 }
@@ -4,5 +4,6 @@ use std::sync::Mutex;
 pub struct AppState {
     pub health_check_response: String,
     pub visit_count: Mutex<u32>,
+    #[allow(dead_code)]
     pub db: PgPool,
 }
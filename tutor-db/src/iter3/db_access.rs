@@ -1,5 +1,4 @@
 use super::models::Course;
-use chrono::NaiveDateTime;
 use sqlx::postgres::PgPool;
 
 pub async fn get_courses_for_tutor_db(pool: &PgPool, tutor_id: i32) -> Vec<Course> {
@@ -18,7 +17,7 @@ pub async fn get_courses_for_tutor_db(pool: &PgPool, tutor_id: i32) -> Vec<Cours
             course_id: course_row.course_id,
             tutor_id: course_row.tutor_id,
             course_name: course_row.course_name.clone(),
-            posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+            posted_time: course_row.posted_time,
         })
         .collect()
 }
@@ -38,7 +37,7 @@ pub async fn get_course_details_db(pool: &PgPool, tutor_id: i32, course_id: i32)
         course_id: course_row.course_id,
         tutor_id: course_row.tutor_id,
         course_name: course_row.course_name.clone(),
-        posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+        posted_time: course_row.posted_time,
     }
 }
 
@@ -58,6 +57,6 @@ pub async fn post_new_course_db(pool: &PgPool, new_course: Course) -> Course {
         course_id: course_row.course_id,
         tutor_id: course_row.tutor_id,
         course_name: course_row.course_name.clone(),
-        posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+        posted_time: course_row.posted_time,
     }
 }
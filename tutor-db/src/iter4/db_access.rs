@@ -1,83 +1,896 @@
-use super::models::Course;
-use chrono::NaiveDateTime;
+use super::models::{
+    CreateReview, CreateStudent, CreateTutor, Enrollment, PublicStudent, Review, Student,
+    StudentCredentials, Tutor, TutorCredentials, UpdateReview, UpdateStudent, UpdateTutor,
+};
+use sqlx::error::Error as SQLxError;
 use sqlx::postgres::PgPool;
+use tracing::instrument;
+use tutor_common::errors::EzyTutorError;
+use tutor_common::models::{
+    Course, CourseQuery, CourseSearchHit, CourseSearchQuery, CreateCourse, UpdateCourse,
+    VersionMatch,
+};
 
-use super::errors::EzyTutorError;
-
+#[instrument(skip(pool))]
 pub async fn get_courses_for_tutor_db(
     pool: &PgPool,
     tutor_id: i32,
-) -> Result<Vec<Course>, EzyTutorError> {
+    query: &CourseQuery,
+) -> Result<(Vec<Course>, i64), EzyTutorError> {
+    let total = sqlx::query_scalar!(
+        "SELECT count(*) FROM ezy_course_c5 WHERE tutor_id = $1
+         and ($2::text IS NULL or strpos(lower(course_name), lower($2)) > 0)
+         and ($3 or deleted_at IS NULL)",
+        tutor_id,
+        query.name,
+        query.include_deleted()
+    )
+    .fetch_one(pool)
+    .await?
+    .unwrap_or(0);
+
+    // 정렬 기준은 바인딩할 수 없으므로 CASE 식으로 선택
+    // 평점은 리뷰를 집계하지 않고 trigger가 갱신해 둔 review_count, rating_total로 계산
+    let courses: Vec<Course> = sqlx::query_as!(
+        Course,
+        "SELECT course_id, tutor_id, course_name, course_description, course_format,
+         course_structure, course_duration, course_price, course_language, course_level,
+         course_capacity, posted_time,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         review_count, deleted_at, version
+         FROM ezy_course_c5 WHERE tutor_id = $1
+         and ($2::text IS NULL or strpos(lower(course_name), lower($2)) > 0)
+         and ($7 or deleted_at IS NULL)
+         ORDER BY
+         CASE WHEN $3 = 'course_name' and $4 = 'asc' THEN course_name END ASC,
+         CASE WHEN $3 = 'course_name' and $4 = 'desc' THEN course_name END DESC,
+         CASE WHEN $3 = 'posted_time' and $4 = 'asc' THEN posted_time END ASC,
+         CASE WHEN $3 = 'posted_time' and $4 = 'desc' THEN posted_time END DESC,
+         CASE WHEN $4 = 'asc' THEN course_id END ASC,
+         course_id DESC
+         LIMIT $5 OFFSET $6",
+        tutor_id,
+        query.name,
+        query.sort().as_str(),
+        query.order().as_str(),
+        query.limit(),
+        query.offset(),
+        query.include_deleted()
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok((courses, total))
+}
+
+#[instrument(skip(pool))]
+pub async fn search_courses_db(
+    pool: &PgPool,
+    query: &CourseSearchQuery,
+) -> Result<(Vec<CourseSearchHit>, i64), EzyTutorError> {
+    // 인덱스(ezy_course_c5_search_idx)와 같은 식으로 검색해야 인덱스를 사용함
+    let total = sqlx::query_scalar!(
+        "SELECT count(*) FROM ezy_course_c5
+         WHERE to_tsvector('simple', course_name || ' ' || coalesce(course_description, ''))
+         @@ websearch_to_tsquery('simple', $1)
+         and deleted_at IS NULL",
+        query.q
+    )
+    .fetch_one(pool)
+    .await?
+    .unwrap_or(0);
+
     let course_rows = sqlx::query!(
-        "SELECT tutor_id, course_id, course_name, posted_time FROM
-         ezy_course_c5 where tutor_id = $1",
-        tutor_id
+        r#"SELECT c.*,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         ts_rank(to_tsvector('simple', course_name || ' ' || coalesce(course_description, '')),
+                 search) AS "rank!",
+         ts_headline('simple', course_name || ' ' || coalesce(course_description, ''),
+                     search, 'StartSel=<b>, StopSel=</b>') AS "snippet!"
+         FROM ezy_course_c5 c, websearch_to_tsquery('simple', $1) search
+         WHERE to_tsvector('simple', course_name || ' ' || coalesce(course_description, ''))
+         @@ search and deleted_at IS NULL
+         ORDER BY "rank!" DESC, course_id
+         LIMIT $2 OFFSET $3"#,
+        query.q,
+        query.limit(),
+        query.offset()
     )
     .fetch_all(pool)
     .await?;
 
-    let courses: Vec<Course> = course_rows
-        .iter()
-        .map(|course_row| Course {
-            course_id: course_row.course_id,
-            tutor_id: course_row.tutor_id,
-            course_name: course_row.course_name.clone(),
-            posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+    let hits = course_rows
+        .into_iter()
+        .map(|course_row| CourseSearchHit {
+            course: Course {
+                course_id: course_row.course_id,
+                tutor_id: course_row.tutor_id,
+                course_name: course_row.course_name,
+                course_description: course_row.course_description,
+                course_format: course_row.course_format,
+                course_structure: course_row.course_structure,
+                course_duration: course_row.course_duration,
+                course_price: course_row.course_price,
+                course_language: course_row.course_language,
+                course_level: course_row.course_level,
+                course_capacity: course_row.course_capacity,
+                posted_time: course_row.posted_time,
+                average_rating: course_row.average_rating,
+                review_count: course_row.review_count,
+                deleted_at: course_row.deleted_at,
+                version: course_row.version,
+            },
+            rank: course_row.rank,
+            snippet: course_row.snippet,
         })
         .collect();
 
-    match courses.len() {
-        0 => Err(EzyTutorError::NotFound(
-            "Courses not found for tutor".into(),
-        )),
-        _ => Ok(courses),
-    }
+    Ok((hits, total))
 }
 
+#[instrument(skip(pool))]
 pub async fn get_course_details_db(
     pool: &PgPool,
     tutor_id: i32,
     course_id: i32,
 ) -> Result<Course, EzyTutorError> {
-    let course_row = sqlx::query!(
-        "SELECT tutor_id, course_id, course_name, posted_time FROM
-         ezy_course_c5 WHERE tutor_id = $1 and course_id = $2",
+    sqlx::query_as!(
+        Course,
+        "SELECT course_id, tutor_id, course_name, course_description, course_format,
+         course_structure, course_duration, course_price, course_language, course_level,
+         course_capacity, posted_time,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         review_count, deleted_at, version
+         FROM ezy_course_c5 WHERE tutor_id = $1 and course_id = $2 and deleted_at IS NULL",
         tutor_id,
         course_id
     )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| EzyTutorError::NotFound("Course is not found".into()))
+}
+
+#[instrument(skip_all, fields(tutor_id = new_course.tutor_id))]
+pub async fn post_new_course_db(
+    pool: &PgPool,
+    new_course: CreateCourse,
+) -> Result<Course, EzyTutorError> {
+    let course_row = sqlx::query_as!(
+        Course,
+        "INSERT INTO ezy_course_c5 (tutor_id, course_name, course_description,
+         course_format, course_structure, course_duration, course_price,
+         course_language, course_level, course_capacity)
+         values ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+         returning course_id, tutor_id, course_name, course_description, course_format,
+         course_structure, course_duration, course_price, course_language, course_level,
+         course_capacity, posted_time,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         review_count, deleted_at, version",
+        new_course.tutor_id,
+        new_course.course_name,
+        new_course.course_description,
+        new_course.course_format,
+        new_course.course_structure,
+        new_course.course_duration,
+        new_course.course_price,
+        new_course.course_language,
+        new_course.course_level,
+        new_course.course_capacity
+    )
     .fetch_one(pool)
-    .await;
+    .await
+    .map_err(|err| match err {
+        SQLxError::Database(db_err) if db_err.is_foreign_key_violation() => {
+            EzyTutorError::InvalidInput("Tutor does not exist".into())
+        }
+        err => err.into(),
+    })?;
+
+    Ok(course_row)
+}
+
+/// 버전 조건 때문에 수정/삭제된 행이 없을 때, course가 없는지(404) 버전이 다른지(412) 구분한다.
+async fn course_missing_or_stale(pool: &PgPool, tutor_id: i32, course_id: i32) -> EzyTutorError {
+    match get_course_details_db(pool, tutor_id, course_id).await {
+        Ok(_) => {
+            EzyTutorError::PreconditionFailed("Course has been modified by another request".into())
+        }
+        Err(err) => err,
+    }
+}
+
+#[instrument(skip(pool, update_course))]
+pub async fn update_course_details_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    update_course: UpdateCourse,
+    expected: &VersionMatch,
+) -> Result<Course, EzyTutorError> {
+    let course_row = sqlx::query_as!(
+        Course,
+        "UPDATE ezy_course_c5 SET course_name = COALESCE($3, course_name),
+         course_description = COALESCE($4, course_description),
+         course_format = COALESCE($5, course_format),
+         course_structure = COALESCE($6, course_structure),
+         course_duration = COALESCE($7, course_duration),
+         course_price = COALESCE($8, course_price),
+         course_language = COALESCE($9, course_language),
+         course_level = COALESCE($10, course_level),
+         course_capacity = COALESCE($11, course_capacity),
+         version = version + 1
+         WHERE tutor_id = $1 and course_id = $2 and deleted_at IS NULL
+         and ($12::int[] IS NULL or version = ANY($12))
+         returning course_id, tutor_id, course_name, course_description, course_format,
+         course_structure, course_duration, course_price, course_language, course_level,
+         course_capacity, posted_time,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         review_count, deleted_at, version",
+        tutor_id,
+        course_id,
+        update_course.course_name,
+        update_course.course_description,
+        update_course.course_format,
+        update_course.course_structure,
+        update_course.course_duration,
+        update_course.course_price,
+        update_course.course_language,
+        update_course.course_level,
+        update_course.course_capacity,
+        expected.versions()
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    match course_row {
+        Some(course) => Ok(course),
+        None => Err(course_missing_or_stale(pool, tutor_id, course_id).await),
+    }
+}
+
+#[instrument(skip(pool))]
+pub async fn delete_course_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    expected: &VersionMatch,
+) -> Result<String, EzyTutorError> {
+    // 삭제 시각만 기록하고, 보관 기간이 지나면 purge_deleted_courses_db가 영구 삭제
+    // 삭제 전에 받은 ETag로 수정하지 못하도록 버전도 올림
+    let result = sqlx::query!(
+        "UPDATE ezy_course_c5 SET deleted_at = now(), version = version + 1
+         WHERE tutor_id = $1 and course_id = $2 and deleted_at IS NULL
+         and ($3::int[] IS NULL or version = ANY($3))",
+        tutor_id,
+        course_id,
+        expected.versions()
+    )
+    .execute(pool)
+    .await?;
+
+    match result.rows_affected() {
+        0 => Err(course_missing_or_stale(pool, tutor_id, course_id).await),
+        deleted => Ok(format!("Deleted {} record", deleted)),
+    }
+}
+
+#[instrument(skip(pool))]
+pub async fn restore_course_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+) -> Result<Course, EzyTutorError> {
+    sqlx::query_as!(
+        Course,
+        "UPDATE ezy_course_c5 SET deleted_at = NULL, version = version + 1
+         WHERE tutor_id = $1 and course_id = $2 and deleted_at IS NOT NULL
+         returning course_id, tutor_id, course_name, course_description, course_format,
+         course_structure, course_duration, course_price, course_language, course_level,
+         course_capacity, posted_time,
+         round(rating_total::numeric / nullif(review_count, 0), 2)::float8 AS average_rating,
+         review_count, deleted_at, version",
+        tutor_id,
+        course_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| EzyTutorError::NotFound("Deleted course is not found".into()))
+}
 
-    if let Ok(course_row) = course_row {
-        Ok(Course {
-            course_id: course_row.course_id,
-            tutor_id: course_row.tutor_id,
-            course_name: course_row.course_name.clone(),
-            posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+/// 삭제한 지 `retention_days`일이 지난 course를 영구 삭제하고 삭제한 수를 돌려준다.
+/// 수강 신청과 리뷰도 외래 키(on delete cascade)로 함께 삭제됨
+#[instrument(skip(pool))]
+pub async fn purge_deleted_courses_db(
+    pool: &PgPool,
+    retention_days: i32,
+) -> Result<u64, EzyTutorError> {
+    let result = sqlx::query!(
+        "DELETE FROM ezy_course_c5
+         WHERE deleted_at < now() - make_interval(days => $1)",
+        retention_days
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[instrument(skip_all)]
+pub async fn get_all_tutors_db(pool: &PgPool) -> Result<Vec<Tutor>, EzyTutorError> {
+    let tutor_rows = sqlx::query!(
+        "SELECT tutor_id, tutor_name, tutor_pic_url, tutor_profile FROM ezy_tutor
+         ORDER BY tutor_id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let tutors: Vec<Tutor> = tutor_rows
+        .iter()
+        .map(|tutor_row| Tutor {
+            tutor_id: tutor_row.tutor_id,
+            tutor_name: tutor_row.tutor_name.clone(),
+            tutor_pic_url: tutor_row.tutor_pic_url.clone(),
+            tutor_profile: tutor_row.tutor_profile.clone(),
+        })
+        .collect();
+
+    Ok(tutors)
+}
+
+#[instrument(skip(pool))]
+pub async fn get_tutor_details_db(pool: &PgPool, tutor_id: i32) -> Result<Tutor, EzyTutorError> {
+    let tutor_row = sqlx::query!(
+        "SELECT tutor_id, tutor_name, tutor_pic_url, tutor_profile FROM ezy_tutor
+         WHERE tutor_id = $1",
+        tutor_id
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(tutor_row) = tutor_row {
+        Ok(Tutor {
+            tutor_id: tutor_row.tutor_id,
+            tutor_name: tutor_row.tutor_name.clone(),
+            tutor_pic_url: tutor_row.tutor_pic_url.clone(),
+            tutor_profile: tutor_row.tutor_profile.clone(),
         })
     } else {
-        Err(EzyTutorError::NotFound("Course is not found".into()))
+        Err(EzyTutorError::NotFound("Tutor is not found".into()))
     }
 }
 
-pub async fn post_new_course_db(
+#[instrument(skip(pool, update_tutor))]
+pub async fn update_tutor_details_db(
     pool: &PgPool,
-    new_course: Course,
-) -> Result<Course, EzyTutorError> {
-    let course_row = sqlx::query!(
-        "INSERT INTO ezy_course_c5 (course_id, tutor_id, course_name) values ($1, $2, $3)
-         returning tutor_id, course_id, course_name, posted_time",
-        new_course.course_id,
-        new_course.tutor_id,
-        new_course.course_name
+    tutor_id: i32,
+    update_tutor: UpdateTutor,
+) -> Result<Tutor, EzyTutorError> {
+    let tutor_row = sqlx::query!(
+        "UPDATE ezy_tutor SET tutor_name = COALESCE($2, tutor_name),
+         tutor_pic_url = COALESCE($3, tutor_pic_url),
+         tutor_profile = COALESCE($4, tutor_profile)
+         WHERE tutor_id = $1
+         returning tutor_id, tutor_name, tutor_pic_url, tutor_profile",
+        tutor_id,
+        update_tutor.tutor_name,
+        update_tutor.tutor_pic_url,
+        update_tutor.tutor_profile
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if let Some(tutor_row) = tutor_row {
+        Ok(Tutor {
+            tutor_id: tutor_row.tutor_id,
+            tutor_name: tutor_row.tutor_name.clone(),
+            tutor_pic_url: tutor_row.tutor_pic_url.clone(),
+            tutor_profile: tutor_row.tutor_profile.clone(),
+        })
+    } else {
+        Err(EzyTutorError::NotFound("Tutor is not found".into()))
+    }
+}
+
+#[instrument(skip(pool))]
+pub async fn delete_tutor_db(pool: &PgPool, tutor_id: i32) -> Result<String, EzyTutorError> {
+    let mut tx = pool.begin().await?;
+
+    // 삭제된 course는 보관 기간이 남았더라도 tutor와 함께 영구 삭제
+    // 삭제되지 않은 course가 남아 있으면 외래 키 때문에 tutor를 삭제할 수 없음
+    sqlx::query!(
+        "DELETE FROM ezy_course_c5 WHERE tutor_id = $1 and deleted_at IS NOT NULL",
+        tutor_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let result = sqlx::query!("DELETE FROM ezy_tutor WHERE tutor_id = $1", tutor_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| match err {
+            SQLxError::Database(db_err) if db_err.is_foreign_key_violation() => {
+                EzyTutorError::Conflict("Tutor still has courses".into())
+            }
+            err => err.into(),
+        })?;
+
+    match result.rows_affected() {
+        0 => Err(EzyTutorError::NotFound("Tutor is not found".into())),
+        deleted => {
+            tx.commit().await?;
+            Ok(format!("Deleted {} record", deleted))
+        }
+    }
+}
+
+#[instrument(skip(pool, new_tutor, password_hash))]
+pub async fn post_new_tutor_account_db(
+    pool: &PgPool,
+    new_tutor: CreateTutor,
+    username: &str,
+    password_hash: &str,
+) -> Result<Tutor, EzyTutorError> {
+    let mut tx = pool.begin().await?;
+
+    let tutor_row = sqlx::query!(
+        "INSERT INTO ezy_tutor (tutor_name, tutor_pic_url, tutor_profile) values ($1, $2, $3)
+         returning tutor_id, tutor_name, tutor_pic_url, tutor_profile",
+        new_tutor.tutor_name,
+        new_tutor.tutor_pic_url,
+        new_tutor.tutor_profile
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "INSERT INTO ezy_tutor_auth (username, tutor_id, password_hash) values ($1, $2, $3)",
+        username,
+        tutor_row.tutor_id,
+        password_hash
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        SQLxError::Database(db_err) if db_err.is_unique_violation() => {
+            EzyTutorError::InvalidInput("Username is already taken".into())
+        }
+        err => err.into(),
+    })?;
+
+    tx.commit().await?;
+
+    Ok(Tutor {
+        tutor_id: tutor_row.tutor_id,
+        tutor_name: tutor_row.tutor_name.clone(),
+        tutor_pic_url: tutor_row.tutor_pic_url.clone(),
+        tutor_profile: tutor_row.tutor_profile.clone(),
+    })
+}
+
+#[instrument(skip(pool))]
+pub async fn get_tutor_credentials_db(
+    pool: &PgPool,
+    username: &str,
+) -> Result<Option<TutorCredentials>, EzyTutorError> {
+    let credentials_row = sqlx::query!(
+        "SELECT tutor_id, password_hash FROM ezy_tutor_auth WHERE username = $1",
+        username
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(credentials_row.map(|credentials_row| TutorCredentials {
+        tutor_id: credentials_row.tutor_id,
+        password_hash: credentials_row.password_hash,
+    }))
+}
+
+fn student_conflict(err: SQLxError) -> EzyTutorError {
+    match err {
+        SQLxError::Database(db_err) if db_err.is_unique_violation() => {
+            EzyTutorError::Conflict("Email is already registered".into())
+        }
+        err => err.into(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn get_all_students_db(pool: &PgPool) -> Result<Vec<PublicStudent>, EzyTutorError> {
+    let students = sqlx::query_as!(
+        PublicStudent,
+        "SELECT student_id, student_name FROM ezy_student ORDER BY student_id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(students)
+}
+
+#[instrument(skip(pool))]
+pub async fn get_student_details_db(
+    pool: &PgPool,
+    student_id: i32,
+) -> Result<Student, EzyTutorError> {
+    sqlx::query_as!(
+        Student,
+        "SELECT student_id, student_name, student_email FROM ezy_student WHERE student_id = $1",
+        student_id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| EzyTutorError::NotFound("Student is not found".into()))
+}
+
+#[instrument(skip_all)]
+pub async fn post_new_student_account_db(
+    pool: &PgPool,
+    new_student: CreateStudent,
+    password_hash: &str,
+) -> Result<Student, EzyTutorError> {
+    sqlx::query_as!(
+        Student,
+        "INSERT INTO ezy_student (student_name, student_email, password_hash) values ($1, $2, $3)
+         returning student_id, student_name, student_email",
+        new_student.student_name,
+        new_student.student_email,
+        password_hash
     )
     .fetch_one(pool)
+    .await
+    .map_err(student_conflict)
+}
+
+#[instrument(skip_all)]
+pub async fn get_student_credentials_db(
+    pool: &PgPool,
+    student_email: &str,
+) -> Result<Option<StudentCredentials>, EzyTutorError> {
+    let credentials_row = sqlx::query!(
+        "SELECT student_id, password_hash FROM ezy_student WHERE student_email = $1",
+        student_email
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(credentials_row.map(|credentials_row| StudentCredentials {
+        student_id: credentials_row.student_id,
+        password_hash: credentials_row.password_hash,
+    }))
+}
+
+#[instrument(skip(pool, update_student))]
+pub async fn update_student_details_db(
+    pool: &PgPool,
+    student_id: i32,
+    update_student: UpdateStudent,
+) -> Result<Student, EzyTutorError> {
+    sqlx::query_as!(
+        Student,
+        "UPDATE ezy_student SET student_name = COALESCE($2, student_name),
+         student_email = COALESCE($3, student_email)
+         WHERE student_id = $1
+         returning student_id, student_name, student_email",
+        student_id,
+        update_student.student_name,
+        update_student.student_email
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(student_conflict)?
+    .ok_or_else(|| EzyTutorError::NotFound("Student is not found".into()))
+}
+
+#[instrument(skip(pool))]
+pub async fn delete_student_db(pool: &PgPool, student_id: i32) -> Result<String, EzyTutorError> {
+    // 수강 신청 기록은 외래 키(on delete cascade)로 함께 삭제됨
+    let result = sqlx::query!("DELETE FROM ezy_student WHERE student_id = $1", student_id)
+        .execute(pool)
+        .await?;
+
+    match result.rows_affected() {
+        0 => Err(EzyTutorError::NotFound("Student is not found".into())),
+        deleted => Ok(format!("Deleted {} record", deleted)),
+    }
+}
+
+#[instrument(skip(pool))]
+pub async fn post_new_enrollment_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    student_id: i32,
+) -> Result<Enrollment, EzyTutorError> {
+    let mut tx = pool.begin().await?;
+
+    // course 행을 잠가 같은 course에 동시에 신청해도 정원을 넘지 않도록 함
+    let course_row = sqlx::query!(
+        "SELECT course_capacity FROM ezy_course_c5
+         WHERE tutor_id = $1 and course_id = $2 and deleted_at IS NULL FOR UPDATE",
+        tutor_id,
+        course_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| EzyTutorError::NotFound("Course is not found".into()))?;
+
+    let enrolled_row = sqlx::query!(
+        r#"SELECT count(*) AS "enrolled!",
+         coalesce(bool_or(student_id = $2), false) AS "already_enrolled!"
+         FROM ezy_enrollment WHERE course_id = $1"#,
+        course_id,
+        student_id
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if enrolled_row.already_enrolled {
+        return Err(EzyTutorError::Conflict(
+            "Student is already enrolled in the course".into(),
+        ));
+    }
+    if let Some(capacity) = course_row.course_capacity {
+        if enrolled_row.enrolled >= i64::from(capacity) {
+            return Err(EzyTutorError::Conflict("Course is full".into()));
+        }
+    }
+
+    let enrollment_row = sqlx::query!(
+        "INSERT INTO ezy_enrollment (course_id, student_id) values ($1, $2)
+         returning enrolled_time",
+        course_id,
+        student_id
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        SQLxError::Database(db_err) if db_err.is_foreign_key_violation() => {
+            EzyTutorError::NotFound("Student is not found".into())
+        }
+        err => err.into(),
+    })?;
+
+    tx.commit().await?;
+
+    Ok(Enrollment {
+        tutor_id,
+        course_id,
+        student_id,
+        enrolled_time: enrollment_row.enrolled_time,
+    })
+}
+
+#[instrument(skip(pool))]
+pub async fn get_course_enrollments_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+) -> Result<Vec<Enrollment>, EzyTutorError> {
+    // course가 없으면 404, 신청자가 없으면 빈 목록
+    get_course_details_db(pool, tutor_id, course_id).await?;
+
+    let enrollments = sqlx::query_as!(
+        Enrollment,
+        "SELECT c.tutor_id, e.course_id, e.student_id, e.enrolled_time
+         FROM ezy_enrollment e JOIN ezy_course_c5 c ON c.course_id = e.course_id
+         WHERE c.tutor_id = $1 and e.course_id = $2
+         ORDER BY e.enrolled_time, e.student_id",
+        tutor_id,
+        course_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(enrollments)
+}
+
+#[instrument(skip(pool))]
+pub async fn get_student_enrollments_db(
+    pool: &PgPool,
+    student_id: i32,
+) -> Result<Vec<Enrollment>, EzyTutorError> {
+    get_student_details_db(pool, student_id).await?;
+
+    let enrollments = sqlx::query_as!(
+        Enrollment,
+        "SELECT c.tutor_id, e.course_id, e.student_id, e.enrolled_time
+         FROM ezy_enrollment e JOIN ezy_course_c5 c ON c.course_id = e.course_id
+         WHERE e.student_id = $1 and c.deleted_at IS NULL
+         ORDER BY e.enrolled_time, e.course_id",
+        student_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(enrollments)
+}
+
+#[instrument(skip(pool))]
+pub async fn delete_enrollment_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    student_id: i32,
+) -> Result<String, EzyTutorError> {
+    let result = sqlx::query!(
+        "DELETE FROM ezy_enrollment e USING ezy_course_c5 c
+         WHERE c.course_id = e.course_id and c.deleted_at IS NULL
+         and c.tutor_id = $1 and e.course_id = $2 and e.student_id = $3",
+        tutor_id,
+        course_id,
+        student_id
+    )
+    .execute(pool)
     .await?;
 
-    Ok(Course {
-        course_id: course_row.course_id,
-        tutor_id: course_row.tutor_id,
-        course_name: course_row.course_name.clone(),
-        posted_time: Some(NaiveDateTime::from(course_row.posted_time.unwrap())),
+    match result.rows_affected() {
+        0 => Err(EzyTutorError::NotFound("Enrollment is not found".into())),
+        deleted => Ok(format!("Deleted {} record", deleted)),
+    }
+}
+
+/// 리뷰를 쓰거나 고칠 수 없는 이유를 찾는다.
+/// course가 없으면 404, 수강 신청하지 않은 학생이면 403, 그 외에는 `missing`이 없다는 404
+async fn review_rejected(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    student_id: i32,
+    missing: &str,
+) -> EzyTutorError {
+    let enrolled = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM ezy_enrollment e
+           WHERE e.course_id = c.course_id and e.student_id = $3) AS "enrolled!"
+           FROM ezy_course_c5 c
+           WHERE c.tutor_id = $1 and c.course_id = $2 and c.deleted_at IS NULL"#,
+        tutor_id,
+        course_id,
+        student_id
+    )
+    .fetch_optional(pool)
+    .await;
+
+    match enrolled {
+        Ok(None) => EzyTutorError::NotFound("Course is not found".into()),
+        Ok(Some(false)) => {
+            EzyTutorError::Forbidden("Only students enrolled in the course can review it".into())
+        }
+        Ok(Some(true)) => EzyTutorError::NotFound(format!("{} is not found", missing)),
+        Err(err) => err.into(),
+    }
+}
+
+#[instrument(skip(pool, new_review), fields(student_id = new_review.student_id))]
+pub async fn post_new_review_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    new_review: CreateReview,
+) -> Result<Review, EzyTutorError> {
+    // tutor의 course가 아니거나 수강 신청하지 않은 학생이면 행이 추가되지 않음
+    let review_row = sqlx::query!(
+        "INSERT INTO ezy_review (course_id, student_id, rating, review_text)
+         SELECT c.course_id, e.student_id, $4, $5 FROM ezy_course_c5 c
+         JOIN ezy_enrollment e ON e.course_id = c.course_id and e.student_id = $3
+         WHERE c.tutor_id = $1 and c.course_id = $2 and c.deleted_at IS NULL
+         returning course_id, student_id, rating, review_text, posted_time, updated_time",
+        tutor_id,
+        course_id,
+        new_review.student_id,
+        new_review.rating,
+        new_review.review_text
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| match err {
+        SQLxError::Database(db_err) if db_err.is_unique_violation() => {
+            EzyTutorError::Conflict("Student has already reviewed the course".into())
+        }
+        err => err.into(),
+    })?;
+    let Some(review_row) = review_row else {
+        return Err(
+            review_rejected(pool, tutor_id, course_id, new_review.student_id, "Course").await,
+        );
+    };
+
+    Ok(Review {
+        tutor_id,
+        course_id: review_row.course_id,
+        student_id: review_row.student_id,
+        rating: review_row.rating,
+        review_text: review_row.review_text,
+        posted_time: review_row.posted_time,
+        updated_time: review_row.updated_time,
     })
 }
+
+#[instrument(skip(pool))]
+pub async fn get_course_reviews_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+) -> Result<Vec<Review>, EzyTutorError> {
+    // course가 없으면 404, 리뷰가 없으면 빈 목록
+    get_course_details_db(pool, tutor_id, course_id).await?;
+
+    let reviews = sqlx::query_as!(
+        Review,
+        "SELECT c.tutor_id, r.course_id, r.student_id, r.rating, r.review_text,
+         r.posted_time, r.updated_time
+         FROM ezy_review r JOIN ezy_course_c5 c ON c.course_id = r.course_id
+         WHERE c.tutor_id = $1 and r.course_id = $2
+         ORDER BY r.posted_time DESC, r.student_id",
+        tutor_id,
+        course_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(reviews)
+}
+
+#[instrument(skip(pool, update_review))]
+pub async fn update_review_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    student_id: i32,
+    update_review: UpdateReview,
+) -> Result<Review, EzyTutorError> {
+    let review_row = sqlx::query!(
+        "UPDATE ezy_review r SET rating = COALESCE($4, r.rating),
+         review_text = COALESCE($5, r.review_text),
+         updated_time = now()
+         FROM ezy_course_c5 c, ezy_enrollment e
+         WHERE c.course_id = r.course_id and c.deleted_at IS NULL
+         and e.course_id = r.course_id and e.student_id = r.student_id
+         and c.tutor_id = $1 and r.course_id = $2 and r.student_id = $3
+         returning r.course_id, r.student_id, r.rating, r.review_text,
+         r.posted_time, r.updated_time",
+        tutor_id,
+        course_id,
+        student_id,
+        update_review.rating,
+        update_review.review_text
+    )
+    .fetch_optional(pool)
+    .await?;
+    let Some(review_row) = review_row else {
+        return Err(review_rejected(pool, tutor_id, course_id, student_id, "Review").await);
+    };
+
+    Ok(Review {
+        tutor_id,
+        course_id: review_row.course_id,
+        student_id: review_row.student_id,
+        rating: review_row.rating,
+        review_text: review_row.review_text,
+        posted_time: review_row.posted_time,
+        updated_time: review_row.updated_time,
+    })
+}
+
+/// 수강을 취소한 학생도 자신이 쓴 리뷰는 지울 수 있도록 수강 여부는 확인하지 않음
+#[instrument(skip(pool))]
+pub async fn delete_review_db(
+    pool: &PgPool,
+    tutor_id: i32,
+    course_id: i32,
+    student_id: i32,
+) -> Result<String, EzyTutorError> {
+    let result = sqlx::query!(
+        "DELETE FROM ezy_review r USING ezy_course_c5 c
+         WHERE c.course_id = r.course_id and c.deleted_at IS NULL
+         and c.tutor_id = $1 and r.course_id = $2 and r.student_id = $3",
+        tutor_id,
+        course_id,
+        student_id
+    )
+    .execute(pool)
+    .await?;
+
+    match result.rows_affected() {
+        0 => Err(EzyTutorError::NotFound("Review is not found".into())),
+        deleted => Ok(format!("Deleted {} record", deleted)),
+    }
+}
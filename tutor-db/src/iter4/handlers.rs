@@ -1,9 +1,35 @@
 use super::db_access::*;
-use super::errors::EzyTutorError;
-use super::models::Course;
+use super::models::{
+    CreateEnrollment, CreateReview, CreateStudent, CreateTutor, Enrollment, LoginStudent,
+    LoginTutor, PublicStudent, RegisterStudent, RegisterTutor, Review, Student, Tutor,
+    UpdateReview, UpdateStudent, UpdateTutor,
+};
+use super::password::{hash_password, verify_password};
 use super::state::AppState;
-use actix_web::{web, HttpResponse};
+use actix_web::{http::header, web, HttpResponse};
+use actix_web_validation::Validated;
+use tracing::instrument;
+use tutor_common::auth::{
+    AuthenticatedStudent, AuthenticatedTutor, JwtKeys, StudentTokenResponse, TokenResponse,
+};
+use tutor_common::errors::{EzyTutorError, ProblemDetails};
 
+const HEALTH: &str = "health";
+const TUTOR: &str = "tutor";
+const AUTH: &str = "auth";
+const STUDENT: &str = "student";
+const ENROLLMENT: &str = "enrollment";
+const REVIEW: &str = "review";
+
+/// health check with visit count
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = HEALTH,
+    responses(
+        (status = 200, description = "service is up", body = String),
+    )
+)]
 pub async fn health_check_handler(app_state: web::Data<AppState>) -> HttpResponse {
     let health_check_response = &app_state.health_check_response;
     let mut visit_count = app_state.visit_count.lock().unwrap();
@@ -14,47 +40,681 @@ pub async fn health_check_handler(app_state: web::Data<AppState>) -> HttpRespons
     HttpResponse::Ok().json(&response)
 }
 
-pub async fn get_courses_for_tutor(
+/// get all tutors
+#[utoipa::path(
+    get,
+    path = "/tutors/",
+    tag = TUTOR,
+    responses(
+        (status = 200, description = "tutors found from storage", body = Vec<Tutor>),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_all_tutors(app_state: web::Data<AppState>) -> Result<HttpResponse, EzyTutorError> {
+    get_all_tutors_db(&app_state.db)
+        .await
+        .map(|tutors| HttpResponse::Ok().json(tutors))
+}
+
+/// get tutor by given tutor id
+#[utoipa::path(
+    get,
+    path = "/tutors/{tutor_id}",
+    tag = TUTOR,
+    responses(
+        (status = 200, description = "tutor found from storage", body = Tutor),
+        (status = 404, description = "tutor not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+    ),
+)]
+#[instrument(skip_all)]
+pub async fn get_tutor_details(
+    app_state: web::Data<AppState>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let tutor_id = params.into_inner();
+    get_tutor_details_db(&app_state.db, tutor_id)
+        .await
+        .map(|tutor| HttpResponse::Ok().json(tutor))
+}
+
+/// update own tutor profile
+#[utoipa::path(
+    method(put, patch),
+    path = "/tutors/{tutor_id}",
+    tag = TUTOR,
+    request_body = UpdateTutor,
+    responses(
+        (status = 200, description = "tutor updated successfully", body = Tutor),
+        (status = 400, description = "invalid tutor fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "tutor can only modify own profile", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "tutor not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn update_tutor_details(
+    tutor: AuthenticatedTutor,
+    app_state: web::Data<AppState>,
+    Validated(update_tutor): Validated<web::Json<UpdateTutor>>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let tutor_id = params.into_inner();
+    tutor.ensure_owner(tutor_id)?;
+    update_tutor_details_db(&app_state.db, tutor_id, update_tutor.try_into()?)
+        .await
+        .map(|tutor| HttpResponse::Ok().json(tutor))
+}
+
+/// delete own tutor profile with courses already deleted
+#[utoipa::path(
+    delete,
+    path = "/tutors/{tutor_id}",
+    tag = TUTOR,
+    responses(
+        (status = 200, description = "tutor deleted successfully", body = String),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "tutor can only modify own profile", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "tutor not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "tutor still has courses that are not deleted", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_tutor(
+    tutor: AuthenticatedTutor,
     app_state: web::Data<AppState>,
     params: web::Path<i32>,
 ) -> Result<HttpResponse, EzyTutorError> {
     let tutor_id = params.into_inner();
-    get_courses_for_tutor_db(&app_state.db, tutor_id)
+    tutor.ensure_owner(tutor_id)?;
+    delete_tutor_db(&app_state.db, tutor_id)
+        .await
+        .map(|resp| HttpResponse::Ok().json(resp))
+}
+
+/// register tutor with login credentials and issue an access token
+#[utoipa::path(
+    post,
+    path = "/auth/register",
+    tag = AUTH,
+    request_body = RegisterTutor,
+    responses(
+        (status = 201, description = "tutor registered successfully", body = TokenResponse,
+            headers(("Location" = String, description = "URL of the created tutor"))),
+        (status = 400, description = "invalid fields or username already taken", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn register_tutor(
+    Validated(new_account): Validated<web::Json<RegisterTutor>>,
+    app_state: web::Data<AppState>,
+    keys: web::Data<JwtKeys>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let new_account: RegisterTutor = new_account.try_into()?;
+    // argon2 해시는 CPU를 많이 쓰므로 워커 스레드를 막지 않도록 분리
+    let password = new_account.password.clone();
+    let password_hash = web::block(move || hash_password(&password)).await??;
+
+    let tutor = post_new_tutor_account_db(
+        &app_state.db,
+        CreateTutor::from(&new_account),
+        &new_account.username,
+        &password_hash,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created()
+        .insert_header((header::LOCATION, format!("/tutors/{}", tutor.tutor_id)))
+        .json(TokenResponse::new(
+            tutor.tutor_id,
+            keys.issue(tutor.tutor_id)?,
+        )))
+}
+
+/// issue an access token for username and password
+#[utoipa::path(
+    post,
+    path = "/auth/login",
+    tag = AUTH,
+    request_body = LoginTutor,
+    responses(
+        (status = 200, description = "logged in successfully", body = TokenResponse),
+        (status = 400, description = "invalid fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "invalid username or password", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn login_tutor(
+    Validated(login): Validated<web::Json<LoginTutor>>,
+    app_state: web::Data<AppState>,
+    keys: web::Data<JwtKeys>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let login: LoginTutor = login.try_into()?;
+    let invalid_credentials = || EzyTutorError::Unauthorized("Invalid username or password".into());
+
+    let credentials = get_tutor_credentials_db(&app_state.db, &login.username)
+        .await?
+        .ok_or_else(invalid_credentials)?;
+    let password_hash = credentials.password_hash;
+    let verified = web::block(move || verify_password(&login.password, &password_hash)).await??;
+    if !verified {
+        return Err(invalid_credentials());
+    }
+
+    Ok(HttpResponse::Ok().json(TokenResponse::new(
+        credentials.tutor_id,
+        keys.issue(credentials.tutor_id)?,
+    )))
+}
+
+/// get all students without their email addresses
+#[utoipa::path(
+    get,
+    path = "/students/",
+    tag = STUDENT,
+    responses(
+        (status = 200, description = "students found from storage", body = Vec<PublicStudent>),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn get_all_students(
+    app_state: web::Data<AppState>,
+) -> Result<HttpResponse, EzyTutorError> {
+    get_all_students_db(&app_state.db)
+        .await
+        .map(|students| HttpResponse::Ok().json(students))
+}
+
+/// get own student profile by given student id
+#[utoipa::path(
+    get,
+    path = "/students/{student_id}",
+    tag = STUDENT,
+    responses(
+        (status = 200, description = "student found from storage", body = Student),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only read own profile", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "student not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_student_details(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let student_id = params.into_inner();
+    student.ensure_owner(student_id)?;
+    get_student_details_db(&app_state.db, student_id)
+        .await
+        .map(|student| HttpResponse::Ok().json(student))
+}
+
+/// register student with login credentials and issue an access token
+#[utoipa::path(
+    post,
+    path = "/students/",
+    tag = STUDENT,
+    request_body = RegisterStudent,
+    responses(
+        (status = 201, description = "student registered successfully", body = StudentTokenResponse,
+            headers(("Location" = String, description = "URL of the created student"))),
+        (status = 400, description = "invalid student fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "email is already registered", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn post_new_student(
+    Validated(new_account): Validated<web::Json<RegisterStudent>>,
+    app_state: web::Data<AppState>,
+    keys: web::Data<JwtKeys>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let new_account: RegisterStudent = new_account.try_into()?;
+    // argon2 해시는 CPU를 많이 쓰므로 워커 스레드를 막지 않도록 분리
+    let password = new_account.password.clone();
+    let password_hash = web::block(move || hash_password(&password)).await??;
+
+    let student = post_new_student_account_db(
+        &app_state.db,
+        CreateStudent::from(&new_account),
+        &password_hash,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created()
+        .insert_header((
+            header::LOCATION,
+            format!("/students/{}", student.student_id),
+        ))
+        .json(StudentTokenResponse::new(
+            student.student_id,
+            keys.issue_student(student.student_id)?,
+        )))
+}
+
+/// issue a student access token for email and password
+#[utoipa::path(
+    post,
+    path = "/auth/students/login",
+    tag = AUTH,
+    request_body = LoginStudent,
+    responses(
+        (status = 200, description = "logged in successfully", body = StudentTokenResponse),
+        (status = 400, description = "invalid fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "invalid email or password", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+#[instrument(skip_all)]
+pub async fn login_student(
+    Validated(login): Validated<web::Json<LoginStudent>>,
+    app_state: web::Data<AppState>,
+    keys: web::Data<JwtKeys>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let login: LoginStudent = login.try_into()?;
+    let invalid_credentials = || EzyTutorError::Unauthorized("Invalid email or password".into());
+
+    let credentials = get_student_credentials_db(&app_state.db, &login.student_email)
+        .await?
+        .ok_or_else(invalid_credentials)?;
+    let password_hash = credentials.password_hash;
+    let verified = web::block(move || verify_password(&login.password, &password_hash)).await??;
+    if !verified {
+        return Err(invalid_credentials());
+    }
+
+    Ok(HttpResponse::Ok().json(StudentTokenResponse::new(
+        credentials.student_id,
+        keys.issue_student(credentials.student_id)?,
+    )))
+}
+
+/// update student details
+#[utoipa::path(
+    method(put, patch),
+    path = "/students/{student_id}",
+    tag = STUDENT,
+    request_body = UpdateStudent,
+    responses(
+        (status = 200, description = "student updated successfully", body = Student),
+        (status = 400, description = "invalid student fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only modify own profile", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "student not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "email is already registered", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn update_student_details(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    Validated(update_student): Validated<web::Json<UpdateStudent>>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let student_id = params.into_inner();
+    student.ensure_owner(student_id)?;
+    update_student_details_db(&app_state.db, student_id, update_student.try_into()?)
         .await
-        .map(|courses| HttpResponse::Ok().json(courses))
+        .map(|student| HttpResponse::Ok().json(student))
 }
 
-pub async fn get_course_details(
+/// delete student with all enrollments
+#[utoipa::path(
+    delete,
+    path = "/students/{student_id}",
+    tag = STUDENT,
+    responses(
+        (status = 200, description = "student deleted successfully", body = String),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only delete own profile", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "student not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_student(
+    student: AuthenticatedStudent,
     app_state: web::Data<AppState>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let student_id = params.into_inner();
+    student.ensure_owner(student_id)?;
+    delete_student_db(&app_state.db, student_id)
+        .await
+        .map(|resp| HttpResponse::Ok().json(resp))
+}
+
+/// get courses a student is enrolled in
+#[utoipa::path(
+    get,
+    path = "/students/{student_id}/enrollments",
+    tag = ENROLLMENT,
+    responses(
+        (status = 200, description = "enrollments of the student", body = Vec<Enrollment>),
+        (status = 404, description = "student not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("student_id", description = "Unique id of student"),
+    ),
+)]
+#[instrument(skip_all)]
+pub async fn get_student_enrollments(
+    app_state: web::Data<AppState>,
+    params: web::Path<i32>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let student_id = params.into_inner();
+    get_student_enrollments_db(&app_state.db, student_id)
+        .await
+        .map(|enrollments| HttpResponse::Ok().json(enrollments))
+}
+
+/// enroll a student in a course
+#[utoipa::path(
+    post,
+    path = "/courses/{tutor_id}/{course_id}/enrollments",
+    tag = ENROLLMENT,
+    request_body = CreateEnrollment,
+    responses(
+        (status = 201, description = "student enrolled successfully", body = Enrollment,
+            headers(("Location" = String, description = "URL of the created enrollment"))),
+        (status = 400, description = "invalid enrollment fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only enroll themselves", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course or student not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "course is full or student is already enrolled", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn post_new_enrollment(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    Validated(new_enrollment): Validated<web::Json<CreateEnrollment>>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    let new_enrollment: CreateEnrollment = new_enrollment.try_into()?;
+    student.ensure_owner(new_enrollment.student_id)?;
+    post_new_enrollment_db(
+        &app_state.db,
+        tutor_id,
+        course_id,
+        new_enrollment.student_id,
+    )
+    .await
+    .map(|enrollment| {
+        HttpResponse::Created()
+            .insert_header((
+                header::LOCATION,
+                format!(
+                    "/courses/{}/{}/enrollments/{}",
+                    enrollment.tutor_id, enrollment.course_id, enrollment.student_id
+                ),
+            ))
+            .json(enrollment)
+    })
+}
+
+/// get students enrolled in own course
+#[utoipa::path(
+    get,
+    path = "/courses/{tutor_id}/{course_id}/enrollments",
+    tag = ENROLLMENT,
+    responses(
+        (status = 200, description = "enrollments of the course", body = Vec<Enrollment>),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "course belongs to another tutor", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn get_course_enrollments(
+    tutor: AuthenticatedTutor,
+    app_state: web::Data<AppState>,
+    params: web::Path<(i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id) = params.into_inner();
+    tutor.ensure_owner(tutor_id)?;
+    get_course_enrollments_db(&app_state.db, tutor_id, course_id)
+        .await
+        .map(|enrollments| HttpResponse::Ok().json(enrollments))
+}
+
+/// withdraw a student from a course
+#[utoipa::path(
+    delete,
+    path = "/courses/{tutor_id}/{course_id}/enrollments/{student_id}",
+    tag = ENROLLMENT,
+    responses(
+        (status = 200, description = "enrollment withdrawn successfully", body = String),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only withdraw own enrollment", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "enrollment not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_enrollment(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    params: web::Path<(i32, i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id, student_id) = params.into_inner();
+    student.ensure_owner(student_id)?;
+    delete_enrollment_db(&app_state.db, tutor_id, course_id, student_id)
+        .await
+        .map(|resp| HttpResponse::Ok().json(resp))
+}
+
+/// review a course
+#[utoipa::path(
+    post,
+    path = "/courses/{tutor_id}/{course_id}/reviews",
+    tag = REVIEW,
+    request_body = CreateReview,
+    responses(
+        (status = 201, description = "review posted successfully", body = Review,
+            headers(("Location" = String, description = "URL of the created review"))),
+        (status = 400, description = "invalid review fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only review as themselves a course they are enrolled in", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "student has already reviewed the course", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn post_new_review(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    Validated(new_review): Validated<web::Json<CreateReview>>,
     params: web::Path<(i32, i32)>,
 ) -> Result<HttpResponse, EzyTutorError> {
     let (tutor_id, course_id) = params.into_inner();
-    get_course_details_db(&app_state.db, tutor_id, course_id)
+    let new_review: CreateReview = new_review.try_into()?;
+    student.ensure_owner(new_review.student_id)?;
+    post_new_review_db(&app_state.db, tutor_id, course_id, new_review)
         .await
-        .map(|course| HttpResponse::Ok().json(course))
+        .map(|review| {
+            HttpResponse::Created()
+                .insert_header((
+                    header::LOCATION,
+                    format!(
+                        "/courses/{}/{}/reviews/{}",
+                        review.tutor_id, review.course_id, review.student_id
+                    ),
+                ))
+                .json(review)
+        })
 }
 
-pub async fn post_new_course(
-    new_course: web::Json<Course>,
+/// get reviews of a course, newest first
+#[utoipa::path(
+    get,
+    path = "/courses/{tutor_id}/{course_id}/reviews",
+    tag = REVIEW,
+    responses(
+        (status = 200, description = "reviews of the course", body = Vec<Review>),
+        (status = 404, description = "course not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+    ),
+)]
+#[instrument(skip_all)]
+pub async fn get_course_reviews(
     app_state: web::Data<AppState>,
+    params: web::Path<(i32, i32)>,
 ) -> Result<HttpResponse, EzyTutorError> {
-    post_new_course_db(&app_state.db, new_course.into())
+    let (tutor_id, course_id) = params.into_inner();
+    get_course_reviews_db(&app_state.db, tutor_id, course_id)
         .await
-        .map(|course| HttpResponse::Ok().json(course))
+        .map(|reviews| HttpResponse::Ok().json(reviews))
+}
+
+/// edit a review
+#[utoipa::path(
+    method(put, patch),
+    path = "/courses/{tutor_id}/{course_id}/reviews/{student_id}",
+    tag = REVIEW,
+    request_body = UpdateReview,
+    responses(
+        (status = 200, description = "review updated successfully", body = Review),
+        (status = 400, description = "invalid review fields", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only edit own review of a course they are enrolled in", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "course or review not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn update_review(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    Validated(update_review): Validated<web::Json<UpdateReview>>,
+    params: web::Path<(i32, i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id, student_id) = params.into_inner();
+    student.ensure_owner(student_id)?;
+    let update_review: UpdateReview = update_review.try_into()?;
+    update_review_db(
+        &app_state.db,
+        tutor_id,
+        course_id,
+        student_id,
+        update_review,
+    )
+    .await
+    .map(|review| HttpResponse::Ok().json(review))
+}
+
+/// delete a review
+#[utoipa::path(
+    delete,
+    path = "/courses/{tutor_id}/{course_id}/reviews/{student_id}",
+    tag = REVIEW,
+    responses(
+        (status = 200, description = "review deleted successfully", body = String),
+        (status = 401, description = "missing or invalid bearer token", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 403, description = "student can only delete own review", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "review not found", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, description = "unexpected storage or server error", body = ProblemDetails, content_type = "application/problem+json"),
+    ),
+    params(
+        ("tutor_id", description = "Unique id of tutor"),
+        ("course_id", description = "Unique id of course"),
+        ("student_id", description = "Unique id of student"),
+    ),
+    security(("bearer_auth" = []))
+)]
+#[instrument(skip_all)]
+pub async fn delete_review(
+    student: AuthenticatedStudent,
+    app_state: web::Data<AppState>,
+    params: web::Path<(i32, i32, i32)>,
+) -> Result<HttpResponse, EzyTutorError> {
+    let (tutor_id, course_id, student_id) = params.into_inner();
+    student.ensure_owner(student_id)?;
+    delete_review_db(&app_state.db, tutor_id, course_id, student_id)
+        .await
+        .map(|resp| HttpResponse::Ok().json(resp))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use actix_web::http::StatusCode;
-    use chrono::NaiveDate;
     use dotenv::dotenv;
     use sqlx::postgres::PgPool;
     use std::env;
     use std::sync::Mutex;
+    use tutor_common::models::VersionMatch;
 
     #[actix_rt::test]
-    async fn get_all_courses_success() {
+    async fn get_all_tutors_success() {
         dotenv().ok();
 
         let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
@@ -65,15 +725,14 @@ mod tests {
             visit_count: Mutex::new(0),
             db: pool,
         });
-        let tutor_id: web::Path<i32> = web::Path::from(1);
 
-        let response = get_courses_for_tutor(app_state, tutor_id).await.unwrap();
+        let response = get_all_tutors(app_state).await.unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[actix_rt::test]
-    async fn get_course_detail_test() {
+    async fn get_tutor_detail_test() {
         dotenv().ok();
 
         let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
@@ -84,21 +743,392 @@ mod tests {
             visit_count: Mutex::new(0),
             db: pool,
         });
-        let params: web::Path<(i32, i32)> = web::Path::from((1, 1));
+        let params: web::Path<i32> = web::Path::from(1);
 
-        let response = get_course_details(app_state, params).await.unwrap();
+        let response = get_tutor_details(app_state, params).await.unwrap();
 
         assert_eq!(response.status(), StatusCode::OK);
     }
 
     #[actix_rt::test]
-    async fn post_course_success() {
+    async fn post_and_delete_tutor_success() {
         dotenv().ok();
 
         let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
         let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
 
-        let savepool = pool.clone();
+        let app_state = web::Data::new(AppState {
+            health_check_response: "".to_string(),
+            visit_count: Mutex::new(0),
+            db: pool.clone(),
+        });
+
+        let new_tutor = post_new_tutor_account_db(
+            &pool,
+            CreateTutor {
+                tutor_name: "Third tutor".into(),
+                tutor_pic_url: "http://tutor.s3.com/ssdfds".into(),
+                tutor_profile: "Experienced tutor in Rust".into(),
+            },
+            &format!("third{}", chrono::Utc::now().timestamp_micros()),
+            "unused",
+        )
+        .await
+        .unwrap();
+        let tutor = AuthenticatedTutor {
+            tutor_id: new_tutor.tutor_id,
+        };
+        let params: web::Path<i32> = web::Path::from(new_tutor.tutor_id);
+
+        let response = delete_tutor(tutor, app_state, params).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[actix_rt::test]
+    async fn delete_tutor_with_courses_test() {
+        use actix_web::ResponseError;
+        use tutor_common::models::CreateCourse;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+
+        let tutor = post_new_tutor_account_db(
+            &pool,
+            CreateTutor {
+                tutor_name: "Tutor with courses".into(),
+                tutor_pic_url: "http://tutor.s3.com/courses".into(),
+                tutor_profile: "Tutor leaving with deleted courses".into(),
+            },
+            &format!("leaving{}", chrono::Utc::now().timestamp_micros()),
+            "unused",
+        )
+        .await
+        .unwrap();
+        let course = post_new_course_db(
+            &pool,
+            CreateCourse {
+                tutor_id: tutor.tutor_id,
+                course_name: "Course of leaving tutor".into(),
+                course_description: None,
+                course_format: None,
+                course_structure: None,
+                course_duration: None,
+                course_price: None,
+                course_language: None,
+                course_level: None,
+                course_capacity: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        // 삭제되지 않은 course가 있으면 409
+        let error = delete_tutor_db(&pool, tutor.tutor_id).await.unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::CONFLICT);
+
+        // 삭제된 course는 보관 기간과 관계없이 함께 영구 삭제
+        delete_course_db(&pool, tutor.tutor_id, course.course_id, &VersionMatch::Any)
+            .await
+            .unwrap();
+        delete_tutor_db(&pool, tutor.tutor_id).await.unwrap();
+        let remaining = sqlx::query_scalar!(
+            "SELECT count(*) FROM ezy_course_c5 WHERE course_id = $1",
+            course.course_id
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(remaining, Some(0));
+    }
+
+    #[actix_rt::test]
+    async fn register_login_and_ownership_test() {
+        use super::super::routes::{auth_routes, tutor_routes};
+        use actix_web::{test, App};
+        use chrono::Utc;
+        use serde_json::Value;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(AppState {
+                    health_check_response: "".to_string(),
+                    visit_count: Mutex::new(0),
+                    db: pool,
+                }))
+                .app_data(web::Data::new(JwtKeys::new(b"test secret")))
+                .configure(auth_routes)
+                .configure(tutor_routes),
+        )
+        .await;
+
+        // 가입
+        let username = format!("tutor{}", Utc::now().timestamp_micros());
+        let req = test::TestRequest::post()
+            .uri("/auth/register")
+            .set_json(serde_json::json!({
+                "username": username,
+                "password": "correct horse battery",
+                "tutor_name": "Registered tutor",
+                "tutor_pic_url": "http://tutor.s3.com/registered",
+                "tutor_profile": "Tutor with login",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let registered: Value = test::read_body_json(resp).await;
+        let tutor_id = registered["tutor_id"].as_i64().unwrap();
+
+        // 로그인 정보 없는 tutor는 만들 수 없음
+        let req = test::TestRequest::post()
+            .uri("/tutors/")
+            .set_json(serde_json::json!({
+                "tutor_name": "Anonymous tutor",
+                "tutor_pic_url": "http://tutor.s3.com/anonymous",
+                "tutor_profile": "Tutor without login",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // 같은 username으로 다시 가입할 수 없음
+        let req = test::TestRequest::post()
+            .uri("/auth/register")
+            .set_json(serde_json::json!({
+                "username": username,
+                "password": "another password",
+                "tutor_name": "Duplicated tutor",
+                "tutor_pic_url": "http://tutor.s3.com/duplicated",
+                "tutor_profile": "Tutor with same username",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // 로그인
+        let req = test::TestRequest::post()
+            .uri("/auth/login")
+            .set_json(serde_json::json!({"username": username, "password": "wrong password"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::post()
+            .uri("/auth/login")
+            .set_json(
+                serde_json::json!({"username": username, "password": "correct horse battery"}),
+            )
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let login: Value = test::read_body_json(resp).await;
+        assert_eq!(login["tutor_id"].as_i64().unwrap(), tutor_id);
+        let auth = (
+            header::AUTHORIZATION,
+            format!("Bearer {}", login["access_token"].as_str().unwrap()),
+        );
+
+        // 다른 tutor는 수정할 수 없고, 자기 자신은 수정/삭제할 수 있음
+        let req = test::TestRequest::patch()
+            .uri("/tutors/1")
+            .insert_header(auth.clone())
+            .set_json(serde_json::json!({"tutor_name": "Hijacked"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/tutors/{}", tutor_id))
+            .set_json(serde_json::json!({"tutor_name": "Anonymous"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/tutors/{}", tutor_id))
+            .insert_header(auth.clone())
+            .set_json(serde_json::json!({"tutor_name": "Renamed tutor"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/tutors/{}", tutor_id))
+            .insert_header(auth)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_rt::test]
+    async fn student_login_and_ownership_test() {
+        use super::super::routes::{auth_routes, student_routes};
+        use actix_web::{test, App};
+        use chrono::Utc;
+        use serde_json::Value;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+
+        let keys = web::Data::new(JwtKeys::new(b"test secret"));
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(AppState {
+                    health_check_response: "".to_string(),
+                    visit_count: Mutex::new(0),
+                    db: pool,
+                }))
+                .app_data(web::Data::new(JwtKeys::new(b"test secret")))
+                .configure(auth_routes)
+                .configure(student_routes),
+        )
+        .await;
+
+        let suffix = Utc::now().timestamp_micros();
+        let mut student_ids = vec![];
+        for name in ["owner", "other"] {
+            let req = test::TestRequest::post()
+                .uri("/students/")
+                .set_json(serde_json::json!({
+                    "student_name": name,
+                    "student_email": format!("{}{}@login.example.com", name, suffix),
+                    "password": "correct horse battery",
+                }))
+                .to_request();
+            let student: Value = test::call_and_read_body_json(&app, req).await;
+            student_ids.push(student["student_id"].as_i64().unwrap());
+        }
+
+        // 비밀번호 없이는 가입할 수 없음
+        let req = test::TestRequest::post()
+            .uri("/students/")
+            .set_json(serde_json::json!({
+                "student_name": "no password",
+                "student_email": format!("nopassword{}@login.example.com", suffix),
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        // 로그인
+        let email = format!("owner{}@login.example.com", suffix);
+        let req = test::TestRequest::post()
+            .uri("/auth/students/login")
+            .set_json(serde_json::json!({"student_email": email, "password": "wrong password"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::post()
+            .uri("/auth/students/login")
+            .set_json(
+                serde_json::json!({"student_email": email, "password": "correct horse battery"}),
+            )
+            .to_request();
+        let login: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(login["student_id"].as_i64().unwrap(), student_ids[0]);
+        let auth = (
+            header::AUTHORIZATION,
+            format!("Bearer {}", login["access_token"].as_str().unwrap()),
+        );
+
+        let owner = format!("/students/{}", student_ids[0]);
+        let other = format!("/students/{}", student_ids[1]);
+
+        // 토큰 없이, 다른 학생의 정보는, tutor 토큰으로는 수정/삭제할 수 없음
+        let req = test::TestRequest::patch()
+            .uri(&owner)
+            .set_json(serde_json::json!({"student_name": "Anonymous"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::patch()
+            .uri(&other)
+            .insert_header(auth.clone())
+            .set_json(serde_json::json!({"student_name": "Hijacked"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::patch()
+            .uri(&owner)
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", keys.issue(student_ids[0] as i32).unwrap()),
+            ))
+            .set_json(serde_json::json!({"student_name": "Tutor in disguise"}))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::delete().uri(&owner).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let req = test::TestRequest::delete()
+            .uri(&other)
+            .insert_header(auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        // 본인은 수정/삭제할 수 있음
+        let req = test::TestRequest::patch()
+            .uri(&owner)
+            .insert_header(auth.clone())
+            .set_json(serde_json::json!({"student_name": "Renamed student"}))
+            .to_request();
+        let student: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(student["student_name"], "Renamed student");
+
+        let req = test::TestRequest::delete()
+            .uri(&owner)
+            .insert_header(auth)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let req = test::TestRequest::delete()
+            .uri(&other)
+            .insert_header((
+                header::AUTHORIZATION,
+                format!(
+                    "Bearer {}",
+                    keys.issue_student(student_ids[1] as i32).unwrap()
+                ),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_rt::test]
+    async fn get_all_tutors_pool_exhausted_failure() {
+        use actix_web::ResponseError;
+        use sqlx::postgres::PgPoolOptions;
+        use std::time::Duration;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPoolOptions::new()
+            .max_connections(1)
+            .acquire_timeout(Duration::from_millis(200))
+            .connect(&database_url)
+            .await
+            .unwrap();
+        // 하나뿐인 커넥션을 붙잡아 풀을 고갈시킴
+        let _connection = pool.acquire().await.unwrap();
 
         let app_state = web::Data::new(AppState {
             health_check_response: "".to_string(),
@@ -106,22 +1136,586 @@ mod tests {
             db: pool.clone(),
         });
 
-        let new_course_msg = Course {
-            course_id: 3,
-            tutor_id: 1,
-            course_name: "Third course".into(),
-            posted_time: NaiveDate::from_ymd_opt(2025, 1, 5)
+        let error = get_all_tutors(app_state).await.unwrap_err();
+
+        assert_eq!(error.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(error
+            .error_response()
+            .headers()
+            .contains_key(header::RETRY_AFTER));
+    }
+
+    #[actix_rt::test]
+    async fn student_enrollment_capacity_test() {
+        use super::super::routes::{course_routes, enrollment_routes, student_routes};
+        use actix_web::{test, App};
+        use chrono::Utc;
+        use serde_json::Value;
+        use tutor_common::models::CreateCourse;
+        use utoipa_actix_web::AppExt;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+        let course = post_new_course_db(
+            &pool,
+            CreateCourse {
+                tutor_id: 1,
+                course_name: "Course with one seat".into(),
+                course_description: None,
+                course_format: None,
+                course_structure: None,
+                course_duration: None,
+                course_price: None,
+                course_language: None,
+                course_level: None,
+                course_capacity: Some(1),
+            },
+        )
+        .await
+        .unwrap();
+
+        // enrollment 경로가 /courses scope에 가려지지 않는지 함께 확인
+        let keys = web::Data::new(JwtKeys::new(b"test secret"));
+        let app = test::init_service(
+            App::new()
+                .into_utoipa_app()
+                .app_data(web::Data::new(AppState {
+                    health_check_response: "".to_string(),
+                    visit_count: Mutex::new(0),
+                    db: pool.clone(),
+                }))
+                .app_data(keys.clone())
+                .map(|app| app.configure(enrollment_routes))
+                .configure(course_routes)
+                .into_app()
+                .configure(student_routes),
+        )
+        .await;
+
+        let suffix = Utc::now().timestamp_micros();
+        let mut student_ids = vec![];
+        let mut auths = vec![];
+        for name in ["first", "second"] {
+            let req = test::TestRequest::post()
+                .uri("/students/")
+                .set_json(serde_json::json!({
+                    "student_name": name,
+                    "student_email": format!("{}{}@example.com", name, suffix),
+                    "password": "correct horse battery",
+                }))
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::CREATED);
+            let student: Value = test::read_body_json(resp).await;
+            student_ids.push(student["student_id"].as_i64().unwrap());
+            auths.push((
+                header::AUTHORIZATION,
+                format!("Bearer {}", student["access_token"].as_str().unwrap()),
+            ));
+        }
+
+        // 학생 정보는 본인만 조회하고, 목록에는 이메일이 없음
+        let details = format!("/students/{}", student_ids[0]);
+        let req = test::TestRequest::get().uri(&details).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = test::TestRequest::get()
+            .uri(&details)
+            .insert_header(auths[1].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let req = test::TestRequest::get()
+            .uri(&details)
+            .insert_header(auths[0].clone())
+            .to_request();
+        let student: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(
+            student["student_email"],
+            format!("first{}@example.com", suffix)
+        );
+        let req = test::TestRequest::get().uri("/students/").to_request();
+        let students: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert!(students
+            .iter()
+            .all(|student| student.get("student_email").is_none()));
+
+        // 같은 이메일로 다시 등록할 수 없음
+        let req = test::TestRequest::post()
+            .uri("/students/")
+            .set_json(serde_json::json!({
+                "student_name": "copy",
+                "student_email": format!("first{}@example.com", suffix),
+                "password": "another password",
+            }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        let enrollments = format!("/courses/1/{}/enrollments", course.course_id);
+        let enroll = |student_id: i64, auth: Option<&(header::HeaderName, String)>| {
+            let req = test::TestRequest::post()
+                .uri(&enrollments)
+                .set_json(serde_json::json!({ "student_id": student_id }));
+            match auth {
+                Some(auth) => req.insert_header(auth.clone()).to_request(),
+                None => req.to_request(),
+            }
+        };
+
+        // 토큰 없이, 또는 다른 학생으로는 신청할 수 없음
+        let resp = test::call_service(&app, enroll(student_ids[0], None)).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let resp = test::call_service(&app, enroll(student_ids[0], Some(&auths[1]))).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let resp = test::call_service(&app, enroll(student_ids[0], Some(&auths[0]))).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        assert_eq!(
+            resp.headers()
+                .get(header::LOCATION)
                 .unwrap()
-                .and_hms_opt(20, 30, 11),
+                .to_str()
+                .unwrap(),
+            format!("{}/{}", enrollments, student_ids[0])
+        );
+
+        // 이미 신청한 학생, 정원이 찬 course
+        let resp = test::call_service(&app, enroll(student_ids[0], Some(&auths[0]))).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let resp = test::call_service(&app, enroll(student_ids[1], Some(&auths[1]))).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+
+        // 신청자 목록은 course의 tutor만 조회
+        let tutor_auth = |tutor_id: i32| {
+            (
+                header::AUTHORIZATION,
+                format!("Bearer {}", keys.issue(tutor_id).unwrap()),
+            )
         };
-        let course_param = web::Json(new_course_msg);
-        let response = post_new_course(course_param, app_state).await.unwrap();
+        let req = test::TestRequest::get().uri(&enrollments).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = test::TestRequest::get()
+            .uri(&enrollments)
+            .insert_header(tutor_auth(2))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let req = test::TestRequest::get()
+            .uri(&enrollments)
+            .insert_header(tutor_auth(1))
+            .to_request();
+        let course_enrollments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(course_enrollments.len(), 1);
+        let req = test::TestRequest::get()
+            .uri(&format!("/students/{}/enrollments", student_ids[0]))
+            .to_request();
+        let student_enrollments: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(student_enrollments[0]["course_id"], course.course_id);
+
+        // 본인만 취소할 수 있고, 취소하면 다른 학생이 신청할 수 있음
+        let withdraw = format!("{}/{}", enrollments, student_ids[0]);
+        let req = test::TestRequest::delete().uri(&withdraw).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = test::TestRequest::delete()
+            .uri(&withdraw)
+            .insert_header(auths[1].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let req = test::TestRequest::delete()
+            .uri(&withdraw)
+            .insert_header(auths[0].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = test::call_service(&app, enroll(student_ids[1], Some(&auths[1]))).await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+
+        // 다른 tutor의 경로로는 찾을 수 없음
+        let req = test::TestRequest::get()
+            .uri(&format!("/courses/2/{}/enrollments", course.course_id))
+            .insert_header(tutor_auth(2))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 
-        sqlx::query("ROLLBACK")
-            .execute(&savepool)
+        delete_course_db(&pool, 1, course.course_id, &VersionMatch::Any)
             .await
-            .expect("ROLLBACK failed");
+            .unwrap();
+        for student_id in student_ids {
+            delete_student_db(&pool, student_id as i32).await.unwrap();
+        }
+    }
 
-        assert_eq!(response.status(), StatusCode::OK);
+    #[actix_rt::test]
+    async fn review_rating_aggregate_test() {
+        use super::super::routes::{course_routes, review_routes};
+        use actix_web::{test, App};
+        use chrono::Utc;
+        use serde_json::Value;
+        use tutor_common::models::{CourseQuery, CreateCourse, SortOrder};
+        use utoipa_actix_web::AppExt;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+        let course = post_new_course_db(
+            &pool,
+            CreateCourse {
+                tutor_id: 1,
+                course_name: "Course to review".into(),
+                course_description: None,
+                course_format: None,
+                course_structure: None,
+                course_duration: None,
+                course_price: None,
+                course_language: None,
+                course_level: None,
+                course_capacity: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(course.review_count, 0);
+        assert_eq!(course.average_rating, None);
+
+        let suffix = Utc::now().timestamp_micros();
+        let mut student_ids = vec![];
+        for name in ["first", "second", "outsider"] {
+            // 토큰을 직접 발급하므로 해시 값은 쓰이지 않음
+            let student = post_new_student_account_db(
+                &pool,
+                CreateStudent {
+                    student_name: name.into(),
+                    student_email: format!("{}{}@review.example.com", name, suffix),
+                },
+                "unused",
+            )
+            .await
+            .unwrap();
+            student_ids.push(student.student_id);
+        }
+        for student_id in &student_ids[..2] {
+            post_new_enrollment_db(&pool, 1, course.course_id, *student_id)
+                .await
+                .unwrap();
+        }
+
+        let keys = JwtKeys::new(b"test secret");
+        let auths = student_ids
+            .iter()
+            .map(|student_id| {
+                (
+                    header::AUTHORIZATION,
+                    format!("Bearer {}", keys.issue_student(*student_id).unwrap()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let app = test::init_service(
+            App::new()
+                .into_utoipa_app()
+                .app_data(web::Data::new(AppState {
+                    health_check_response: "".to_string(),
+                    visit_count: Mutex::new(0),
+                    db: pool.clone(),
+                }))
+                .app_data(web::Data::new(JwtKeys::new(b"test secret")))
+                .map(|app| app.configure(review_routes))
+                .configure(course_routes)
+                .into_app(),
+        )
+        .await;
+
+        let reviews = format!("/courses/1/{}/reviews", course.course_id);
+        let post_review = |student_id: i32, rating: i32| {
+            test::TestRequest::post()
+                .uri(&reviews)
+                .set_json(serde_json::json!({ "student_id": student_id, "rating": rating }))
+        };
+
+        // 토큰 없이, 다른 학생으로, 수강 신청하지 않은 학생은 리뷰할 수 없음
+        let resp = test::call_service(&app, post_review(student_ids[0], 5).to_request()).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = post_review(student_ids[0], 5)
+            .insert_header(auths[1].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let req = post_review(student_ids[2], 1)
+            .insert_header(auths[2].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        for ((student_id, auth), rating) in student_ids.iter().zip(&auths).zip([5, 4]) {
+            let req = post_review(*student_id, rating)
+                .insert_header(auth.clone())
+                .to_request();
+            let resp = test::call_service(&app, req).await;
+            assert_eq!(resp.status(), StatusCode::CREATED);
+        }
+
+        // 학생 한 명은 course마다 리뷰 하나만, 평점은 1~5
+        let req = post_review(student_ids[0], 1)
+            .insert_header(auths[0].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+        let req = post_review(student_ids[0], 6)
+            .insert_header(auths[0].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let details = get_course_details_db(&pool, 1, course.course_id)
+            .await
+            .unwrap();
+        assert_eq!(details.review_count, 2);
+        assert_eq!(details.average_rating, Some(4.5));
+
+        // 본인의 리뷰만 고칠 수 있음
+        let second_review = format!("{}/{}", reviews, student_ids[1]);
+        let req = test::TestRequest::patch()
+            .uri(&second_review)
+            .set_json(serde_json::json!({ "rating": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = test::TestRequest::patch()
+            .uri(&second_review)
+            .insert_header(auths[0].clone())
+            .set_json(serde_json::json!({ "rating": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::patch()
+            .uri(&second_review)
+            .insert_header(auths[1].clone())
+            .set_json(serde_json::json!({ "rating": 2, "review_text": "too fast" }))
+            .to_request();
+        let review: Value = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(review["rating"], 2);
+        assert!(review["updated_time"].is_string());
+
+        let (courses, _) = get_courses_for_tutor_db(
+            &pool,
+            1,
+            &CourseQuery {
+                order: Some(SortOrder::Desc),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let listed = courses
+            .iter()
+            .find(|listed| listed.course_id == course.course_id)
+            .unwrap();
+        assert_eq!(listed.average_rating, Some(3.5));
+
+        let req = test::TestRequest::get().uri(&reviews).to_request();
+        let listed_reviews: Vec<Value> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(listed_reviews.len(), 2);
+
+        // 학생이 삭제되어 리뷰가 함께 지워져도 집계가 맞아야 함
+        delete_student_db(&pool, student_ids[1]).await.unwrap();
+        let details = get_course_details_db(&pool, 1, course.course_id)
+            .await
+            .unwrap();
+        assert_eq!(details.review_count, 1);
+        assert_eq!(details.average_rating, Some(5.0));
+
+        // 수강을 취소하면 리뷰를 고칠 수 없지만 지울 수는 있음
+        let first_review = format!("{}/{}", reviews, student_ids[0]);
+        delete_enrollment_db(&pool, 1, course.course_id, student_ids[0])
+            .await
+            .unwrap();
+        let req = test::TestRequest::patch()
+            .uri(&first_review)
+            .insert_header(auths[0].clone())
+            .set_json(serde_json::json!({ "rating": 1 }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let req = test::TestRequest::delete().uri(&first_review).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let req = test::TestRequest::delete()
+            .uri(&first_review)
+            .insert_header(auths[2].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let req = test::TestRequest::delete()
+            .uri(&first_review)
+            .insert_header(auths[0].clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        let details = get_course_details_db(&pool, 1, course.course_id)
+            .await
+            .unwrap();
+        assert_eq!(details.review_count, 0);
+        assert_eq!(details.average_rating, None);
+
+        delete_course_db(&pool, 1, course.course_id, &VersionMatch::Any)
+            .await
+            .unwrap();
+        for student_id in [student_ids[0], student_ids[2]] {
+            delete_student_db(&pool, student_id).await.unwrap();
+        }
+    }
+
+    #[actix_rt::test]
+    async fn soft_delete_restore_and_purge_test() {
+        use super::super::repository::PgCourseRepository;
+        use super::super::routes::course_routes;
+        use actix_web::{test, App};
+        use serde_json::Value;
+        use std::sync::Arc;
+        use tutor_common::models::{CourseQuery, CreateCourse};
+        use tutor_common::repository::CourseRepository;
+        use utoipa_actix_web::AppExt;
+
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+        let course = post_new_course_db(
+            &pool,
+            CreateCourse {
+                tutor_id: 1,
+                course_name: "Course to soft delete".into(),
+                course_description: None,
+                course_format: None,
+                course_structure: None,
+                course_duration: None,
+                course_price: None,
+                course_language: None,
+                course_level: None,
+                course_capacity: None,
+            },
+        )
+        .await
+        .unwrap();
+        let course_id = course.course_id;
+
+        delete_course_db(&pool, 1, course_id, &VersionMatch::Any)
+            .await
+            .unwrap();
+        assert!(get_course_details_db(&pool, 1, course_id).await.is_err());
+        assert!(delete_course_db(&pool, 1, course_id, &VersionMatch::Any)
+            .await
+            .is_err());
+
+        // 기본 목록에서는 빠지고, include_deleted이면 삭제 시각과 함께 포함
+        let find = |include_deleted| {
+            let pool = pool.clone();
+            async move {
+                let query = CourseQuery {
+                    name: Some("Course to soft delete".into()),
+                    include_deleted: Some(include_deleted),
+                    ..Default::default()
+                };
+                get_courses_for_tutor_db(&pool, 1, &query)
+                    .await
+                    .map(|(courses, _)| courses)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|listed| listed.course_id == course_id)
+            }
+        };
+        assert!(find(false).await.is_none());
+        assert!(find(true).await.unwrap().deleted_at.is_some());
+
+        let keys = web::Data::new(JwtKeys::new(b"test secret"));
+        let app = test::init_service(
+            App::new()
+                .into_utoipa_app()
+                .app_data(web::Data::new(JwtKeys::new(b"test secret")))
+                .app_data(web::Data::from(
+                    Arc::new(PgCourseRepository::new(pool.clone())) as Arc<dyn CourseRepository>,
+                ))
+                .configure(course_routes)
+                .into_app(),
+        )
+        .await;
+        let restore = format!("/courses/1/{}/restore", course_id);
+
+        let req = test::TestRequest::post()
+            .uri(&restore)
+            .insert_header((
+                header::AUTHORIZATION,
+                format!("Bearer {}", keys.issue(2).unwrap()),
+            ))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let auth = (
+            header::AUTHORIZATION,
+            format!("Bearer {}", keys.issue(1).unwrap()),
+        );
+        let req = test::TestRequest::post()
+            .uri(&restore)
+            .insert_header(auth.clone())
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        // 삭제와 복구 모두 버전을 올리므로 삭제 전의 ETag로는 수정할 수 없음
+        assert_eq!(
+            resp.headers().get(header::ETAG).unwrap(),
+            &format!("\"{}\"", course.version + 2)
+        );
+        let restored: Value = test::read_body_json(resp).await;
+        assert_eq!(restored["course_id"], course_id);
+        assert_eq!(restored["version"], course.version + 2);
+        assert!(matches!(
+            delete_course_db(
+                &pool,
+                1,
+                course_id,
+                &VersionMatch::Versions(vec![course.version])
+            )
+            .await,
+            Err(EzyTutorError::PreconditionFailed(_))
+        ));
+        assert!(restored.get("deleted_at").is_none());
+        assert!(get_course_details_db(&pool, 1, course_id).await.is_ok());
+
+        // 삭제되지 않은 course는 복구할 수 없음
+        let req = test::TestRequest::post()
+            .uri(&restore)
+            .insert_header(auth)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        // 보관 기간이 지난 course만 영구 삭제
+        delete_course_db(&pool, 1, course_id, &VersionMatch::Any)
+            .await
+            .unwrap();
+        purge_deleted_courses_db(&pool, 30).await.unwrap();
+        assert!(find(true).await.is_some());
+
+        sqlx::query!(
+            "UPDATE ezy_course_c5 SET deleted_at = deleted_at - interval '31 days'
+             WHERE course_id = $1",
+            course_id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(purge_deleted_courses_db(&pool, 30).await.unwrap() >= 1);
+        assert!(find(true).await.is_none());
     }
 }
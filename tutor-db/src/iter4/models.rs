@@ -1,22 +1,264 @@
 use actix_web::web;
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
+use tutor_common::errors::EzyTutorError;
+use utoipa::ToSchema;
+use validator::Validate;
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
-pub struct Course {
-    pub course_id: i32,
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct Tutor {
+    pub tutor_id: i32,
+    pub tutor_name: String,
+    pub tutor_pic_url: String,
+    pub tutor_profile: String,
+}
+
+/// tutor 생성은 /auth/register로만 받으므로 요청 본문으로 쓰지 않음
+#[derive(Debug, Clone)]
+pub struct CreateTutor {
+    pub tutor_name: String,
+    pub tutor_pic_url: String,
+    pub tutor_profile: String,
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct UpdateTutor {
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub tutor_name: Option<String>,
+    #[validate(
+        url(message = "올바른 URL을 입력하세요"),
+        length(max = 200, message = "200 글자 이하로 입력하세요")
+    )]
+    pub tutor_pic_url: Option<String>,
+    #[validate(length(min = 1, max = 2000, message = "1~2000 글자로 입력하세요"))]
+    pub tutor_profile: Option<String>,
+}
+
+impl TryFrom<web::Json<UpdateTutor>> for UpdateTutor {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<UpdateTutor>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct RegisterTutor {
+    #[validate(length(min = 3, max = 50, message = "3~50 글자로 입력하세요"))]
+    pub username: String,
+    #[validate(length(min = 8, max = 128, message = "8~128 글자로 입력하세요"))]
+    pub password: String,
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub tutor_name: String,
+    #[validate(
+        url(message = "올바른 URL을 입력하세요"),
+        length(max = 200, message = "200 글자 이하로 입력하세요")
+    )]
+    pub tutor_pic_url: String,
+    #[validate(length(min = 1, max = 2000, message = "1~2000 글자로 입력하세요"))]
+    pub tutor_profile: String,
+}
+
+impl TryFrom<web::Json<RegisterTutor>> for RegisterTutor {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<RegisterTutor>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+impl From<&RegisterTutor> for CreateTutor {
+    fn from(value: &RegisterTutor) -> Self {
+        CreateTutor {
+            tutor_name: value.tutor_name.clone(),
+            tutor_pic_url: value.tutor_pic_url.clone(),
+            tutor_profile: value.tutor_profile.clone(),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct LoginTutor {
+    #[validate(length(min = 1, max = 50, message = "1~50 글자로 입력하세요"))]
+    pub username: String,
+    #[validate(length(min = 1, max = 128, message = "1~128 글자로 입력하세요"))]
+    pub password: String,
+}
+
+impl TryFrom<web::Json<LoginTutor>> for LoginTutor {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<LoginTutor>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TutorCredentials {
     pub tutor_id: i32,
-    pub course_name: String,
-    pub posted_time: Option<NaiveDateTime>,
+    pub password_hash: String,
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct Student {
+    pub student_id: i32,
+    pub student_name: String,
+    pub student_email: String,
+}
+
+/// 학생 목록에 공개하는 정보. 이메일은 로그인 ID이므로 본인에게만 보여 줌
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct PublicStudent {
+    pub student_id: i32,
+    pub student_name: String,
+}
+
+/// 학생 생성은 POST /students/의 RegisterStudent로만 받으므로 요청 본문으로 쓰지 않음
+#[derive(Debug, Clone)]
+pub struct CreateStudent {
+    pub student_name: String,
+    pub student_email: String,
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct RegisterStudent {
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub student_name: String,
+    #[validate(
+        email(message = "올바른 이메일 주소를 입력하세요"),
+        length(max = 200, message = "200 글자 이하로 입력하세요")
+    )]
+    pub student_email: String,
+    #[validate(length(min = 8, max = 128, message = "8~128 글자로 입력하세요"))]
+    pub password: String,
+}
+
+impl TryFrom<web::Json<RegisterStudent>> for RegisterStudent {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<RegisterStudent>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
 }
 
-impl From<web::Json<Course>> for Course {
-    fn from(value: web::Json<Course>) -> Self {
-        Course {
-            course_id: value.course_id,
-            tutor_id: value.tutor_id,
-            course_name: value.course_name.clone(),
-            posted_time: value.posted_time,
+impl From<&RegisterStudent> for CreateStudent {
+    fn from(value: &RegisterStudent) -> Self {
+        CreateStudent {
+            student_name: value.student_name.clone(),
+            student_email: value.student_email.clone(),
         }
     }
 }
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct LoginStudent {
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub student_email: String,
+    #[validate(length(min = 1, max = 128, message = "1~128 글자로 입력하세요"))]
+    pub password: String,
+}
+
+impl TryFrom<web::Json<LoginStudent>> for LoginStudent {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<LoginStudent>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StudentCredentials {
+    pub student_id: i32,
+    pub password_hash: String,
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct UpdateStudent {
+    #[validate(length(min = 1, max = 200, message = "1~200 글자로 입력하세요"))]
+    pub student_name: Option<String>,
+    #[validate(
+        email(message = "올바른 이메일 주소를 입력하세요"),
+        length(max = 200, message = "200 글자 이하로 입력하세요")
+    )]
+    pub student_email: Option<String>,
+}
+
+impl TryFrom<web::Json<UpdateStudent>> for UpdateStudent {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<UpdateStudent>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct Enrollment {
+    pub tutor_id: i32,
+    pub course_id: i32,
+    pub student_id: i32,
+    #[schema(value_type = String, format = DateTime)]
+    pub enrolled_time: NaiveDateTime,
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct CreateEnrollment {
+    #[validate(range(min = 1, message = "1 이상의 값을 입력하세요"))]
+    pub student_id: i32,
+}
+
+impl TryFrom<web::Json<CreateEnrollment>> for CreateEnrollment {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<CreateEnrollment>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Deserialize, Serialize, ToSchema, Debug, Clone)]
+pub struct Review {
+    pub tutor_id: i32,
+    pub course_id: i32,
+    pub student_id: i32,
+    #[schema(example = 5)]
+    pub rating: i32,
+    pub review_text: Option<String>,
+    #[schema(value_type = String, format = DateTime)]
+    pub posted_time: NaiveDateTime,
+    #[schema(value_type = Option<String>, format = DateTime)]
+    pub updated_time: Option<NaiveDateTime>,
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct CreateReview {
+    #[validate(range(min = 1, message = "1 이상의 값을 입력하세요"))]
+    pub student_id: i32,
+    #[validate(range(min = 1, max = 5, message = "1~5 사이의 값을 입력하세요"))]
+    #[schema(example = 5)]
+    pub rating: i32,
+    #[validate(length(max = 2000, message = "2000 글자 이하로 입력하세요"))]
+    pub review_text: Option<String>,
+}
+
+impl TryFrom<web::Json<CreateReview>> for CreateReview {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<CreateReview>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
+
+#[derive(Deserialize, ToSchema, Debug, Clone, Validate)]
+pub struct UpdateReview {
+    #[validate(range(min = 1, max = 5, message = "1~5 사이의 값을 입력하세요"))]
+    pub rating: Option<i32>,
+    #[validate(length(max = 2000, message = "2000 글자 이하로 입력하세요"))]
+    pub review_text: Option<String>,
+}
+
+impl TryFrom<web::Json<UpdateReview>> for UpdateReview {
+    type Error = EzyTutorError;
+
+    fn try_from(value: web::Json<UpdateReview>) -> Result<Self, Self::Error> {
+        Ok(value.into_inner())
+    }
+}
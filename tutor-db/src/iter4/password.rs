@@ -0,0 +1,20 @@
+use argon2::password_hash::{rand_core::OsRng, PasswordHash, SaltString};
+use argon2::{Argon2, PasswordHasher, PasswordVerifier};
+use tutor_common::errors::EzyTutorError;
+
+/// argon2id로 비밀번호를 해시한다. (PHC 문자열 형식)
+pub fn hash_password(password: &str) -> Result<String, EzyTutorError> {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map(|hash| hash.to_string())
+        .map_err(|error| EzyTutorError::ActixError(error.to_string()))
+}
+
+pub fn verify_password(password: &str, password_hash: &str) -> Result<bool, EzyTutorError> {
+    let parsed_hash = PasswordHash::new(password_hash)
+        .map_err(|error| EzyTutorError::ActixError(error.to_string()))?;
+    Ok(Argon2::default()
+        .verify_password(password.as_bytes(), &parsed_hash)
+        .is_ok())
+}
@@ -0,0 +1,28 @@
+//! 삭제된 course를 보관 기간이 지나면 영구 삭제하는 백그라운드 작업.
+
+use super::db_access::purge_deleted_courses_db;
+use actix_web::rt::{spawn, task::JoinHandle, time::interval};
+use sqlx::postgres::PgPool;
+use std::time::Duration;
+use tutor_common::settings::RetentionSettings;
+
+/// `purge_interval_secs`마다 보관 기간이 지난 course를 영구 삭제한다. 첫 실행은 바로 시작
+pub fn spawn_purge(pool: PgPool, settings: &RetentionSettings) -> JoinHandle<()> {
+    let retention_days = settings.deleted_course_days;
+    let period = Duration::from_secs(settings.purge_interval_secs);
+
+    spawn(async move {
+        let mut ticks = interval(period);
+        loop {
+            ticks.tick().await;
+            match purge_deleted_courses_db(&pool, retention_days).await {
+                Ok(0) => {}
+                Ok(purged) => {
+                    tracing::info!(purged, retention_days, "Purged deleted courses")
+                }
+                // 다음 주기에 다시 시도
+                Err(error) => tracing::warn!(error = %error, "Failed to purge deleted courses"),
+            }
+        }
+    })
+}
@@ -0,0 +1,133 @@
+use super::db_access::*;
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use tutor_common::errors::EzyTutorError;
+use tutor_common::models::{
+    Course, CourseQuery, CourseSearchHit, CourseSearchQuery, CreateCourse, UpdateCourse,
+    VersionMatch,
+};
+use tutor_common::repository::CourseRepository;
+
+/// PostgreSQL에 course를 보관하는 저장소
+pub struct PgCourseRepository {
+    pool: PgPool,
+}
+
+impl PgCourseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        PgCourseRepository { pool }
+    }
+}
+
+#[async_trait]
+impl CourseRepository for PgCourseRepository {
+    async fn get_courses_for_tutor(
+        &self,
+        tutor_id: i32,
+        query: &CourseQuery,
+    ) -> Result<(Vec<Course>, i64), EzyTutorError> {
+        get_courses_for_tutor_db(&self.pool, tutor_id, query).await
+    }
+
+    async fn search_courses(
+        &self,
+        query: &CourseSearchQuery,
+    ) -> Result<(Vec<CourseSearchHit>, i64), EzyTutorError> {
+        search_courses_db(&self.pool, query).await
+    }
+
+    async fn get_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+    ) -> Result<Course, EzyTutorError> {
+        get_course_details_db(&self.pool, tutor_id, course_id).await
+    }
+
+    async fn post_new_course(&self, new_course: CreateCourse) -> Result<Course, EzyTutorError> {
+        post_new_course_db(&self.pool, new_course).await
+    }
+
+    async fn update_course_details(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        update_course: UpdateCourse,
+        expected: &VersionMatch,
+    ) -> Result<Course, EzyTutorError> {
+        update_course_details_db(&self.pool, tutor_id, course_id, update_course, expected).await
+    }
+
+    async fn delete_course(
+        &self,
+        tutor_id: i32,
+        course_id: i32,
+        expected: &VersionMatch,
+    ) -> Result<String, EzyTutorError> {
+        delete_course_db(&self.pool, tutor_id, course_id, expected).await
+    }
+
+    async fn restore_course(&self, tutor_id: i32, course_id: i32) -> Result<Course, EzyTutorError> {
+        restore_course_db(&self.pool, tutor_id, course_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use actix_web::{http::StatusCode, ResponseError};
+    use dotenv::dotenv;
+    use std::env;
+    use std::sync::Arc;
+    use tutor_common::testing::course_api_suite;
+
+    async fn connect() -> PgPool {
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        PgPool::connect(&database_url).await.unwrap()
+    }
+
+    #[actix_rt::test]
+    async fn course_api_postgres() {
+        let repository = PgCourseRepository::new(connect().await);
+
+        course_api_suite(Arc::new(repository), 1).await;
+    }
+
+    #[actix_rt::test]
+    async fn post_course_unknown_tutor_failure() {
+        let repository = PgCourseRepository::new(connect().await);
+
+        let new_course = CreateCourse {
+            tutor_id: 9999,
+            course_name: "Course of unknown tutor".into(),
+            course_description: None,
+            course_format: None,
+            course_structure: None,
+            course_duration: None,
+            course_price: None,
+            course_language: None,
+            course_level: None,
+            course_capacity: None,
+        };
+
+        match repository.post_new_course(new_course).await {
+            Ok(_) => panic!("Posted a course for a tutor that does not exist"),
+            Err(err) => assert_eq!(err.status_code(), StatusCode::BAD_REQUEST),
+        }
+    }
+
+    #[actix_rt::test]
+    async fn get_course_details_closed_pool_failure() {
+        let pool = connect().await;
+        pool.close().await;
+        let repository = PgCourseRepository::new(pool);
+
+        // DB 오류를 404로 감추지 않고 다시 시도할 수 있는 503으로 응답
+        match repository.get_course_details(1, 1).await {
+            Ok(_) => panic!("Fetched a course from a closed pool"),
+            Err(err) => assert_eq!(err.status_code(), StatusCode::SERVICE_UNAVAILABLE),
+        }
+    }
+}
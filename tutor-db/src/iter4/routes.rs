@@ -1,15 +1,80 @@
 use super::handlers::*;
 use actix_web::web;
+use tutor_common::health::{liveness, readiness};
+use tutor_common::metrics::metrics_handler;
+pub use tutor_common::routes::course_routes;
 
 pub fn general_routes(config: &mut web::ServiceConfig) {
-    config.route("/health", web::get().to(health_check_handler));
+    config
+        .route("/health", web::get().to(health_check_handler))
+        .service(liveness)
+        .service(readiness)
+        .service(metrics_handler);
 }
 
-pub fn course_routes(config: &mut web::ServiceConfig) {
+pub fn tutor_routes(config: &mut web::ServiceConfig) {
     config.service(
-        web::scope("/courses")
-            .route("/", web::post().to(post_new_course))
-            .route("/{tutor_id}", web::get().to(get_courses_for_tutor))
-            .route("/{tutor_id}/{course_id}", web::get().to(get_course_details)),
+        web::scope("/tutors")
+            .route("/", web::get().to(get_all_tutors))
+            .route("/{tutor_id}", web::get().to(get_tutor_details))
+            .route("/{tutor_id}", web::put().to(update_tutor_details))
+            .route("/{tutor_id}", web::patch().to(update_tutor_details))
+            .route("/{tutor_id}", web::delete().to(delete_tutor)),
     );
 }
+
+pub fn auth_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/auth")
+            .route("/register", web::post().to(register_tutor))
+            .route("/login", web::post().to(login_tutor))
+            .route("/students/login", web::post().to(login_student)),
+    );
+}
+
+pub fn student_routes(config: &mut web::ServiceConfig) {
+    config.service(
+        web::scope("/students")
+            .route("/", web::post().to(post_new_student))
+            .route("/", web::get().to(get_all_students))
+            .route("/{student_id}", web::get().to(get_student_details))
+            .route("/{student_id}", web::put().to(update_student_details))
+            .route("/{student_id}", web::patch().to(update_student_details))
+            .route("/{student_id}", web::delete().to(delete_student))
+            .route(
+                "/{student_id}/enrollments",
+                web::get().to(get_student_enrollments),
+            ),
+    );
+}
+
+/// `/courses` scope(`course_routes`)는 일치하는 경로가 없으면 404를 돌려주므로
+/// 이 경로들은 `course_routes`보다 먼저 등록해야 한다.
+pub fn enrollment_routes(config: &mut web::ServiceConfig) {
+    config
+        .service(
+            web::resource("/courses/{tutor_id}/{course_id}/enrollments")
+                .route(web::post().to(post_new_enrollment))
+                .route(web::get().to(get_course_enrollments)),
+        )
+        .service(
+            web::resource("/courses/{tutor_id}/{course_id}/enrollments/{student_id}")
+                .route(web::delete().to(delete_enrollment)),
+        );
+}
+
+/// `enrollment_routes`와 같은 이유로 `course_routes`보다 먼저 등록해야 한다.
+pub fn review_routes(config: &mut web::ServiceConfig) {
+    config
+        .service(
+            web::resource("/courses/{tutor_id}/{course_id}/reviews")
+                .route(web::post().to(post_new_review))
+                .route(web::get().to(get_course_reviews)),
+        )
+        .service(
+            web::resource("/courses/{tutor_id}/{course_id}/reviews/{student_id}")
+                .route(web::put().to(update_review))
+                .route(web::patch().to(update_review))
+                .route(web::delete().to(delete_review)),
+        );
+}
@@ -0,0 +1,156 @@
+use sqlx::migrate::Migrator;
+use sqlx::postgres::PgPool;
+
+/// 빌드 시점의 `migrations` 디렉터리를 바이너리에 포함
+pub static MIGRATOR: Migrator = sqlx::migrate!();
+
+/// DB 스키마가 이 바이너리와 호환되는지 확인하고, `run_migrations`이면 마이그레이션을 적용한다.
+///
+/// 바이너리가 모르는 마이그레이션이 이미 적용되어 있으면 (더 새로운 스키마) 에러를 돌려준다.
+/// `run_migrations`이 아닌데 적용되지 않은 마이그레이션이 있어도 에러를 돌려준다.
+/// sqlx 기록 없이 만들어진 DB는 버전을 확인할 수 없으므로 경고만 남긴다.
+pub async fn prepare_schema(pool: &PgPool, run_migrations: bool) -> Result<(), String> {
+    let known_versions = MIGRATOR
+        .iter()
+        .map(|migration| migration.version)
+        .collect::<Vec<_>>();
+
+    let has_migrations_table: bool =
+        sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(pool)
+            .await
+            .map_err(|error| format!("Failed to inspect database schema: {}", error))?;
+
+    let applied_versions: Vec<i64> = if has_migrations_table {
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await
+            .map_err(|error| format!("Failed to read applied migrations: {}", error))?
+    } else {
+        vec![]
+    };
+
+    let unknown_versions = applied_versions
+        .iter()
+        .filter(|version| !known_versions.contains(version))
+        .collect::<Vec<_>>();
+    if !unknown_versions.is_empty() {
+        return Err(format!(
+            "Database schema is newer than this binary (unknown migrations: {:?}). \
+             Deploy a tutor-db build that includes them.",
+            unknown_versions
+        ));
+    }
+
+    let pending_versions = known_versions
+        .iter()
+        .filter(|version| !applied_versions.contains(version))
+        .collect::<Vec<_>>();
+    if pending_versions.is_empty() {
+        return Ok(());
+    }
+
+    // 초기 마이그레이션은 테이블을 지우고 다시 만들므로, sqlx 기록 없이 만들어진 DB에는 적용하지 않음
+    let unmanaged_schema = !has_migrations_table && {
+        sqlx::query_scalar::<_, bool>("SELECT to_regclass('ezy_course_c5') IS NOT NULL")
+            .fetch_one(pool)
+            .await
+            .map_err(|error| format!("Failed to inspect database schema: {}", error))?
+    };
+    if unmanaged_schema {
+        if run_migrations {
+            return Err("Database schema was not created by sqlx migrations; \
+                 refusing to apply them over existing tables"
+                .into());
+        }
+        // 기록이 없으므로 어떤 마이그레이션이 빠졌는지 알 수 없음
+        tracing::warn!(
+            "Database schema was not created by sqlx migrations; skipping version check"
+        );
+        return Ok(());
+    }
+
+    if !run_migrations {
+        return Err(format!(
+            "Database has pending migrations ({:?}). \
+             Set RUN_MIGRATIONS=true to apply them on startup, or run them before deploying.",
+            pending_versions
+        ));
+    }
+
+    tracing::info!(pending = pending_versions.len(), "Applying migrations");
+    MIGRATOR
+        .run(pool)
+        .await
+        .map_err(|error| format!("Failed to apply migrations: {}", error))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dotenv::dotenv;
+    use std::env;
+
+    #[actix_rt::test]
+    async fn migrations_are_not_applied_over_unmanaged_schema() {
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+
+        // 테스트 DB는 마이그레이션 파일을 직접 적용해 만들므로 sqlx 기록이 없음
+        assert!(prepare_schema(&pool, false).await.is_ok());
+        assert!(prepare_schema(&pool, true).await.is_err());
+    }
+
+    #[actix_rt::test]
+    async fn pending_migrations_refuse_startup_without_run_migrations() {
+        dotenv().ok();
+
+        let database_url = env::var("DATABASE_URL").expect("DATABASE_URL is not set in .env file");
+        let admin_pool: PgPool = PgPool::connect(&database_url).await.unwrap();
+        sqlx::query("DROP SCHEMA IF EXISTS schema_pending_test CASCADE")
+            .execute(&admin_pool)
+            .await
+            .unwrap();
+        sqlx::query("CREATE SCHEMA schema_pending_test")
+            .execute(&admin_pool)
+            .await
+            .unwrap();
+
+        // 별도 schema에 첫 마이그레이션만 적용된 기록을 만들어 나머지가 pending 상태가 되게 함
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .after_connect(|conn, _| {
+                Box::pin(async move {
+                    sqlx::query("SET search_path TO schema_pending_test")
+                        .execute(conn)
+                        .await
+                        .map(|_| ())
+                })
+            })
+            .connect(&database_url)
+            .await
+            .unwrap();
+        sqlx::query("CREATE TABLE _sqlx_migrations (version BIGINT PRIMARY KEY)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let first_version = MIGRATOR.iter().next().unwrap().version;
+        sqlx::query("INSERT INTO _sqlx_migrations (version) VALUES ($1)")
+            .bind(first_version)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let result = prepare_schema(&pool, false).await;
+        pool.close().await;
+        sqlx::query("DROP SCHEMA schema_pending_test CASCADE")
+            .execute(&admin_pool)
+            .await
+            .unwrap();
+
+        let message = result.unwrap_err();
+        assert!(message.contains("pending migrations"));
+    }
+}
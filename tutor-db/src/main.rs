@@ -1,4 +1,4 @@
-use std::{fmt, fs::File, io::Write, num::ParseIntError};
+use std::{fmt, fs::File, io::Write};
 
 #[derive(Debug)]
 pub enum MyError {
@@ -1,90 +1,52 @@
-use actix_web::HttpRequest;
-use actix_web::{middleware::Logger, web, App, HttpServer};
+use actix_web::{middleware::from_fn, web, App, HttpServer};
 use actix_web_validation::validator::ValidatorErrorHandlerExt;
 use routes::general_routes;
-use std::borrow::Cow;
-use std::io;
 use std::sync::{Arc, Mutex};
+use std::{io, process};
 use utoipa::OpenApi;
 use utoipa_actix_web::AppExt;
 use utoipa_swagger_ui::SwaggerUi;
-use validator::{ValidationError, ValidationErrors, ValidationErrorsKind};
 
-use crate::errors::CustomErrorResponse;
-
-#[path = "../errors.rs"]
-mod errors;
 #[path = "../handlers.rs"]
 mod handlers;
-#[path = "../models.rs"]
-mod models;
 #[path = "../routes.rs"]
 mod routes;
 #[path = "../state.rs"]
 mod state;
 
 use state::AppState;
-
-fn flatten_errors(
-    errors: &ValidationErrors,
-    path: Option<String>,
-    indent: Option<u16>,
-) -> Vec<(u16, String, &ValidationError)> {
-    errors
-        .errors()
-        .iter()
-        .flat_map(|(&field, err)| {
-            let indent = indent.unwrap_or(0);
-            let actual_path = path
-                .as_ref()
-                .map(|path| [path.as_str(), field].join("."))
-                .unwrap_or_else(|| field.to_owned());
-            match err {
-                ValidationErrorsKind::Field(field_errors) => field_errors
-                    .iter()
-                    .map(|error| (indent, actual_path.clone(), error))
-                    .collect::<Vec<_>>(),
-                ValidationErrorsKind::List(list_error) => list_error
-                    .iter()
-                    .flat_map(|(index, errors)| {
-                        let actual_path = format!("{}[{}]", actual_path.as_str(), index);
-                        flatten_errors(errors, Some(actual_path), Some(indent + 1))
-                    })
-                    .collect::<Vec<_>>(),
-                ValidationErrorsKind::Struct(struct_errors) => {
-                    flatten_errors(struct_errors, Some(actual_path), Some(indent + 1))
-                }
-            }
-        })
-        .collect::<Vec<_>>()
-}
-
-fn error_handler(errors: validator::ValidationErrors, _: &HttpRequest) -> actix_web::Error {
-    CustomErrorResponse {
-        custom_message: "Validation error".to_string(),
-        errors: flatten_errors(&errors, None, None)
-            .iter()
-            .map(|(_, field, err)| {
-                let code = err.code.as_ref();
-
-                format!(
-                    "{}: {}",
-                    field,
-                    err.message.as_ref().unwrap_or(&Cow::Borrowed(code))
-                )
-            })
-            .collect::<Vec<_>>(),
-    }
-    .into()
-}
+use tutor_common::auth::{JwtKeys, SecurityAddon};
+use tutor_common::errors::{problem_details, validation_error_handler};
+use tutor_common::health::Readiness;
+use tutor_common::memory::InMemoryCourseRepository;
+use tutor_common::metrics::{track_requests, FnGauge, Metrics};
+use tutor_common::repository::CourseRepository;
+use tutor_common::settings::Settings;
+use tutor_common::shutdown;
+use tutor_common::telemetry::{self, trace_requests};
 
 #[actix_rt::main]
 async fn main() -> io::Result<()> {
-    env_logger::init();
+    let settings = match Settings::load() {
+        Ok(settings) => settings,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(1);
+        }
+    };
+    telemetry::init(&settings.log.level);
 
     #[derive(OpenApi)]
     #[openapi(
+        paths(
+            tutor_common::health::liveness,
+            tutor_common::health::readiness,
+            tutor_common::metrics::metrics_handler,
+        ),
+        modifiers(&SecurityAddon),
         tags(
+            (name = "health", description = "service health"),
+            (name = "metrics", description = "Prometheus metrics"),
             (name = "course", description = "course management")
         )
     )]
@@ -92,25 +54,80 @@ async fn main() -> io::Result<()> {
 
     // 애플리케이션 상태 초기화
     let shared_data = web::Data::new(AppState {
-        health_check_response: "I'm good. You've alread asked me ".to_string(),
+        health_check_response: settings.server.health_check_response.clone(),
         visit_count: Mutex::new(0),
-        courses: Mutex::new(vec![]),
     });
+    // storage.data_dir가 지정되면 스냅샷/로그 파일로 재시작 후에도 course를 유지
+    let course_store = Arc::new(match &settings.storage.data_dir {
+        Some(data_dir) => InMemoryCourseRepository::open(data_dir, settings.storage.compact_every)?,
+        None => InMemoryCourseRepository::new(),
+    });
+    let purge = settings
+        .features
+        .purge_deleted_courses
+        .then(|| course_store.spawn_purge(&settings.retention));
+    let readiness = web::Data::new(Readiness::new().with("course_store", course_store.clone()));
+    let metrics = web::Data::new(Metrics::new());
+    let store = course_store.clone();
+    metrics.register(FnGauge::new(
+        "course_store_courses",
+        "Number of stored courses that are not deleted",
+        move || store.course_count() as i64,
+    ));
+    let store = course_store.clone();
+    metrics.register(FnGauge::new(
+        "course_store_tutors",
+        "Number of tutors with at least one course that is not deleted",
+        move || store.tutor_count() as i64,
+    ));
+    let course_repository: Arc<dyn CourseRepository> = course_store.clone();
+    // course 생성/수정/삭제는 이 키로 서명된 토큰이 있어야 함 (tutor-db와 같은 키를 쓰면 토큰 공유 가능)
+    let jwt_keys = web::Data::new(JwtKeys::new(settings.auth.jwt_secret.as_bytes()));
 
+    let app_settings = settings.clone();
+    let app_readiness = readiness.clone();
     let app = move || {
-        App::new()
+        let app = App::new()
             .into_utoipa_app()
             .openapi(ApiDoc::openapi())
-            .map(|app| app.wrap(Logger::default()))
+            .map(|app| {
+                app.wrap(from_fn(problem_details))
+                    .wrap(app_settings.cors.cors())
+                    .wrap(from_fn(track_requests))
+                    .wrap(from_fn(trace_requests))
+            })
             .app_data(shared_data.clone())
-            .configure(routes::course_routes())
-            .openapi_service(|api| {
+            .app_data(app_readiness.clone())
+            .app_data(metrics.clone())
+            .app_data(jwt_keys.clone())
+            .app_data(web::Data::from(course_repository.clone()))
+            .configure(routes::course_routes);
+        let app = if app_settings.features.swagger_ui {
+            app.openapi_service(|api| {
                 SwaggerUi::new("/swagger-ui/{_:.*}").url("/api-docs/openapi.json", api)
             })
-            .into_app()
-            .validator_error_handler(Arc::new(error_handler))
+        } else {
+            app
+        };
+        app.into_app()
+            .validator_error_handler(Arc::new(validation_error_handler))
             .configure(general_routes)
     };
 
-    HttpServer::new(app).bind("127.0.0.1:3000")?.run().await
+    let mut server = HttpServer::new(app)
+        .disable_signals()
+        .shutdown_timeout(settings.server.shutdown_timeout_secs);
+    if let Some(workers) = settings.server.workers {
+        server = server.workers(workers);
+    }
+    let server = server.bind(&settings.server.bind_address)?.run();
+    shutdown::serve(server, readiness, &settings.server).await?;
+
+    if let Some(purge) = purge {
+        purge.abort();
+    }
+    // 다음 시작 때 로그를 다시 적용하지 않도록 스냅샷으로 저장
+    course_store.flush()?;
+    tracing::info!("Course store flushed");
+    Ok(())
 }
@@ -1,18 +1,13 @@
 use super::handlers::*;
 use actix_web::web;
-use utoipa_actix_web::service_config::ServiceConfig;
+use tutor_common::health::{liveness, readiness};
+use tutor_common::metrics::metrics_handler;
+pub use tutor_common::routes::course_routes;
 
 pub fn general_routes(config: &mut web::ServiceConfig) {
-    config.route("/health", web::get().to(health_check_handler));
-}
-
-pub fn course_routes() -> impl FnOnce(&mut ServiceConfig) {
-    |config: &mut ServiceConfig| {
-        config.service(
-            utoipa_actix_web::scope("/courses")
-                .service(new_course)
-                .service(get_courses_for_tutor)
-                .service(get_course_detail),
-        );
-    }
+    config
+        .route("/health", web::get().to(health_check_handler))
+        .service(liveness)
+        .service(readiness)
+        .service(metrics_handler);
 }